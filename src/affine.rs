@@ -0,0 +1,192 @@
+use std::ops::{Mul,MulAssign,Neg};
+
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    point::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// An affine transformation consisting of a linear part followed by a translation. Compared to a
+// full homogeneous matrix it is cheaper to compose, apply and invert.
+macro_rules! implement_affine {
+    ($type:ident {
+        linear_type: $linear_type:ident,
+        vector_type: $vector_type:ident,
+        point_type: $point_type:ident
+    }) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+        pub struct $type<T: Base> {
+            pub linear: $linear_type<T>,
+            pub translation: $vector_type<T>,
+        }
+
+        impl<T: Base> $type<T> {
+            pub fn new(linear: $linear_type<T>, translation: $vector_type<T>) -> Self {
+                $type { linear, translation }
+            }
+
+            pub fn identity() -> Self {
+                $type::new($linear_type::identity(), $vector_type::zero())
+            }
+
+            pub fn from_linear(linear: $linear_type<T>) -> Self {
+                $type::new(linear, $vector_type::zero())
+            }
+
+            pub fn from_translation(translation: $vector_type<T>) -> Self {
+                $type::new($linear_type::identity(), translation)
+            }
+
+            pub fn from_scale(scale: $vector_type<T>) -> Self {
+                $type::from_linear($linear_type::from_diagonal(scale))
+            }
+
+            #[inline]
+            pub fn transform_point(&self, p: $point_type<T>) -> $point_type<T> {
+                $point_type::from_vector(self.linear * p.to_vector() + self.translation)
+            }
+
+            #[inline]
+            pub fn transform_vector(&self, v: $vector_type<T>) -> $vector_type<T> {
+                self.linear * v
+            }
+        }
+
+        impl<T: Base + Neg<Output=T>> $type<T> {
+            // Only the linear part has to be inverted, which is much cheaper than inverting the
+            // equivalent homogeneous matrix.
+            pub fn inverse(&self) -> Option<Self> {
+                let linear = self.linear.inverse()?;
+                Some($type::new(linear, -(linear * self.translation)))
+            }
+        }
+
+        impl<T: Base> Default for $type<T> {
+            fn default() -> Self {
+                $type::identity()
+            }
+        }
+
+        // a * a (applies rhs first)
+        implement_binary_operator!(Mul<$type<T>> for $type<T>,
+            fn mul(lhs, rhs) -> $type<T> {
+                $type::new(lhs.linear * rhs.linear, lhs.linear * rhs.translation + lhs.translation)
+            }
+        );
+
+        // a *= a
+        implement_binary_assign_operator!(MulAssign<$type<T>> for $type<T>,
+            fn mul_assign(lhs, rhs) {{
+                *lhs = *lhs * rhs;
+            }}
+        );
+
+        // a * p
+        implement_binary_operator!(Mul<$point_type<T>> for $type<T>,
+            fn mul(affine, point) -> $point_type<T> {
+                affine.transform_point(point)
+            }
+        );
+
+        impl<T: BaseFloat> ApproxEq for $type<T>
+            where T: ApproxEq<Flt=T>
+        {
+            type Flt = T;
+            #[inline]
+            fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+                self.linear.approx_eq(&other.linear, epsilon, ulps) &&
+                self.translation.approx_eq(&other.translation, epsilon, ulps)
+            }
+        }
+    }
+}
+
+implement_affine!(Affine2 { linear_type: Matrix2, vector_type: Vector2, point_type: Point2 });
+implement_affine!(Affine3 { linear_type: Matrix3, vector_type: Vector3, point_type: Point3 });
+
+// --------------------------------------------------------------------------
+// Conversions
+// --------------------------------------------------------------------------
+
+impl<T: Base> From<Affine2<T>> for Matrix3<T> {
+    fn from(a: Affine2<T>) -> Self {
+        let (l, t) = (a.linear, a.translation);
+        Matrix3::from_components_row_major(
+            l.m00    , l.m01    , t.x,
+            l.m10    , l.m11    , t.y,
+            T::zero(), T::zero(), T::one()
+        )
+    }
+}
+
+// The last row of the matrix is ignored and expected to be (0, 0, 1).
+impl<T: Base> From<Matrix3<T>> for Affine2<T> {
+    fn from(m: Matrix3<T>) -> Self {
+        Affine2::new(
+            Matrix2::from_components_row_major(m.m00, m.m01, m.m10, m.m11),
+            Vector2::new(m.m02, m.m12)
+        )
+    }
+}
+
+impl<T: Base> From<Affine3<T>> for Matrix4<T> {
+    fn from(a: Affine3<T>) -> Self {
+        let (l, t) = (a.linear, a.translation);
+        Matrix4::from_components_row_major(
+            l.m00    , l.m01    , l.m02    , t.x,
+            l.m10    , l.m11    , l.m12    , t.y,
+            l.m20    , l.m21    , l.m22    , t.z,
+            T::zero(), T::zero(), T::zero(), T::one()
+        )
+    }
+}
+
+// The last row of the matrix is ignored and expected to be (0, 0, 0, 1).
+impl<T: Base> From<Matrix4<T>> for Affine3<T> {
+    fn from(m: Matrix4<T>) -> Self {
+        Affine3::new(
+            Matrix3::from_components_row_major(
+                m.m00, m.m01, m.m02,
+                m.m10, m.m11, m.m12,
+                m.m20, m.m21, m.m22
+            ),
+            Vector3::new(m.m03, m.m13, m.m23)
+        )
+    }
+}
+
+impl<T: Base> From<Affine2<T>> for Matrix2x3<T> {
+    fn from(a: Affine2<T>) -> Self {
+        let (l, t) = (a.linear, a.translation);
+        Matrix2x3::from_components_row_major(
+            l.m00, l.m01, t.x,
+            l.m10, l.m11, t.y
+        )
+    }
+}
+
+impl<T: Base> From<Matrix2x3<T>> for Affine2<T> {
+    fn from(m: Matrix2x3<T>) -> Self {
+        Affine2::new(
+            Matrix2::from_components_row_major(m.m00, m.m01, m.m10, m.m11),
+            Vector2::new(m.m02, m.m12)
+        )
+    }
+}
+
+impl<T: Base> From<Affine3<T>> for Matrix3x4<T> {
+    fn from(a: Affine3<T>) -> Self {
+        Matrix3x4::from(Matrix4::from(a))
+    }
+}
+
+impl<T: Base> From<Matrix3x4<T>> for Affine3<T> {
+    fn from(m: Matrix3x4<T>) -> Self {
+        Affine3::from(Matrix4::from(m))
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use crate::affine::*;
+    use crate::point::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+
+    type Aff2 = Affine2<f32>;
+    type Aff3 = Affine3<f32>;
+
+    type Mat2 = Matrix2<f32>;
+    type Mat3 = Matrix3<f32>;
+    type Mat4 = Matrix4<f32>;
+
+    type Vec2 = Vector2<f32>;
+    type Vec3 = Vector3<f32>;
+
+    const EPS: f32 = 8.0*f32::EPSILON;
+
+    fn trs() -> Mat4 {
+        Mat4::new_translation(1.0, -2.0, 3.0)
+            * Mat4::new_rotation(Vec3::new(0.0, 0.6, 0.8), 0.9)
+            * Mat4::new_scale(2.0, 0.5, 3.0)
+    }
+
+    // --------------------------------------------------------------------------
+    // Affine3
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn affine3_identity() {
+        let p = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(Aff3::identity().transform_point(p), p);
+        assert_eq!(Aff3::default(), Aff3::identity());
+    }
+
+    #[test]
+    fn affine3_transform() {
+        let a = Aff3::from_translation(Vec3::new(1.0, 2.0, 3.0)) * Aff3::from_scale(Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(a.transform_point(Point3::new(1.0, 1.0, 1.0)), Point3::new(3.0, 4.0, 5.0));
+        assert_eq!(a * Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 4.0, 5.0));
+        assert_eq!(a.transform_vector(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn affine3_composition_matches_matrix4() {
+        let a = Mat4::new_translation(0.5, 0.0, -1.0) * Mat4::new_rotation_x(0.4);
+        let b = trs();
+        let c = Aff3::from(a) * Aff3::from(b);
+        assert!(Mat4::from(c).approx_eq(&(a * b), EPS, 4));
+        let mut d = Aff3::from(a);
+        d *= Aff3::from(b);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn affine3_inverse() {
+        let a = Aff3::from(trs());
+        assert!((a * a.inverse().unwrap()).approx_eq(&Aff3::identity(), EPS, 4));
+        assert!(Mat4::from(a.inverse().unwrap()).approx_eq(&trs().inverse().unwrap(), 1e-5, 4));
+    }
+
+    #[test]
+    fn affine3_inverse_singular() {
+        assert!(Aff3::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn affine3_matrix4_round_trip() {
+        assert_eq!(Mat4::from(Aff3::from(trs())), trs());
+    }
+
+    // --------------------------------------------------------------------------
+    // Affine2
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn affine2_transform() {
+        let r = Mat2::from_components_row_major(0.0, -1.0, 1.0, 0.0);
+        let a = Aff2::new(r, Vec2::new(5.0, 6.0));
+        assert_eq!(a.transform_point(Point2::new(1.0, 0.0)), Point2::new(5.0, 7.0));
+        assert_eq!(a.transform_vector(Vec2::new(1.0, 0.0)), Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn affine2_inverse() {
+        let a = Aff2::new(Mat2::from_components_row_major(2.0, 1.0, -1.0, 3.0), Vec2::new(5.0, 6.0));
+        assert!((a.inverse().unwrap() * a).approx_eq(&Aff2::identity(), EPS, 4));
+    }
+
+    #[test]
+    fn affine2_matrix3_round_trip() {
+        let m = Mat3::from_components_row_major(
+            2.0, 1.0, 5.0,
+           -1.0, 3.0, 6.0,
+            0.0, 0.0, 1.0
+        );
+        assert_eq!(Mat3::from(Aff2::from(m)), m);
+        let p = Point2::new(1.0, 2.0);
+        assert_eq!(Aff2::from(m).transform_point(p), m.transform_point(p));
+    }
+
+    #[test]
+    fn affine_integer() {
+        let a = Affine2::from_translation(Vector2::new(1, 2)) * Affine2::from_scale(Vector2::new(2, 3));
+        assert_eq!(a * Point2::new(1, 1), Point2::new(3, 5));
+    }
+}
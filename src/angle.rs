@@ -0,0 +1,208 @@
+use std::ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg,Rem};
+
+use crate::traits::*;
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+macro_rules! implement_angle {
+    ($type:ident { full_turn: $full_turn:expr }) => {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+        pub struct $type<T: BaseFloat>(pub T);
+
+        impl<T: BaseFloat> $type<T> {
+            #[inline]
+            pub fn zero() -> Self {
+                $type(T::zero())
+            }
+
+            #[inline]
+            pub fn full_turn() -> Self {
+                $type($full_turn)
+            }
+
+            #[inline]
+            pub fn half_turn() -> Self {
+                $type($full_turn / (T::one() + T::one()))
+            }
+
+            #[inline]
+            pub fn sin(self) -> T {
+                T::sin(Rad::from(self).0)
+            }
+
+            #[inline]
+            pub fn cos(self) -> T {
+                T::cos(Rad::from(self).0)
+            }
+
+            #[inline]
+            pub fn tan(self) -> T {
+                T::tan(Rad::from(self).0)
+            }
+
+            #[inline]
+            pub fn sin_cos(self) -> (T, T) {
+                T::sin_cos(Rad::from(self).0)
+            }
+
+            #[inline]
+            pub fn asin(x: T) -> Self {
+                $type::from(Rad(T::asin(x)))
+            }
+
+            #[inline]
+            pub fn acos(x: T) -> Self {
+                $type::from(Rad(T::acos(x)))
+            }
+
+            #[inline]
+            pub fn atan(x: T) -> Self {
+                $type::from(Rad(T::atan(x)))
+            }
+
+            #[inline]
+            pub fn atan2(y: T, x: T) -> Self {
+                $type::from(Rad(T::atan2(y, x)))
+            }
+
+            // Wraps the angle into [0, full turn).
+            pub fn normalize(self) -> Self {
+                let turn = $full_turn;
+                let r = self.0 % turn;
+                let r = if r < T::zero() { r + turn } else { r };
+                // Adding a full turn to a tiny negative remainder can round up to a full turn.
+                $type(if r >= turn { T::zero() } else { r })
+            }
+
+            // Wraps the angle into [-half turn, half turn).
+            pub fn normalize_signed(self) -> Self {
+                let half = $type::<T>::half_turn();
+                $type::normalize(self + half) - half
+            }
+
+            // The signed difference to `other` along the shorter way around the circle.
+            pub fn shortest_difference(self, other: Self) -> Self {
+                (other - self).normalize_signed()
+            }
+        }
+
+        // a + a
+        implement_binary_operator!(Add<$type<T>> for $type<T> where T: BaseFloat,
+            fn add(lhs, rhs) -> $type<T> {
+                $type(lhs.0 + rhs.0)
+            }
+        );
+
+        // a += a
+        implement_binary_assign_operator!(AddAssign<$type<T>> for $type<T> where T: BaseFloat,
+            fn add_assign(lhs, rhs) {{
+                lhs.0 += rhs.0;
+            }}
+        );
+
+        // a - a
+        implement_binary_operator!(Sub<$type<T>> for $type<T> where T: BaseFloat,
+            fn sub(lhs, rhs) -> $type<T> {
+                $type(lhs.0 - rhs.0)
+            }
+        );
+
+        // a -= a
+        implement_binary_assign_operator!(SubAssign<$type<T>> for $type<T> where T: BaseFloat,
+            fn sub_assign(lhs, rhs) {{
+                lhs.0 -= rhs.0;
+            }}
+        );
+
+        // a * s
+        implement_binary_operator!(Mul<T> for $type<T> where T: BaseFloat,
+            fn mul(angle, scalar) -> $type<T> {
+                $type(angle.0 * scalar)
+            }
+        );
+
+        // a *= s
+        implement_binary_assign_operator!(MulAssign<T> for $type<T> where T: BaseFloat,
+            fn mul_assign(angle, scalar) {{
+                angle.0 *= scalar;
+            }}
+        );
+
+        // a / s
+        implement_binary_operator!(Div<T> for $type<T> where T: BaseFloat,
+            fn div(angle, scalar) -> $type<T> {
+                $type(angle.0 / scalar)
+            }
+        );
+
+        // a /= s
+        implement_binary_assign_operator!(DivAssign<T> for $type<T> where T: BaseFloat,
+            fn div_assign(angle, scalar) {{
+                angle.0 /= scalar;
+            }}
+        );
+
+        // a / a
+        implement_binary_operator!(Div<$type<T>> for $type<T> where T: BaseFloat,
+            fn div(lhs, rhs) -> T {
+                lhs.0 / rhs.0
+            }
+        );
+
+        // a % a
+        implement_binary_operator!(Rem<$type<T>> for $type<T> where T: BaseFloat,
+            fn rem(lhs, rhs) -> $type<T> {
+                $type(lhs.0 % rhs.0)
+            }
+        );
+
+        implement_unary_operator!(Neg for $type<T> where T: BaseFloat,
+            fn neg(angle) -> $type<T> {
+                $type(-angle.0)
+            }
+        );
+
+        impl<T: BaseFloat> ApproxEq for $type<T>
+            where T: ApproxEq<Flt=T>
+        {
+            type Flt = T;
+            #[inline]
+            fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+                self.0.approx_eq(&other.0, epsilon, ulps)
+            }
+        }
+
+        // s * a
+        implement_binary_operator_non_generic!(Mul<$type<f32>> for f32,
+            fn mul(scalar, angle) -> $type<f32> {
+                $type(scalar * angle.0)
+            }
+        );
+
+        implement_binary_operator_non_generic!(Mul<$type<f64>> for f64,
+            fn mul(scalar, angle) -> $type<f64> {
+                $type(scalar * angle.0)
+            }
+        );
+    }
+}
+
+implement_angle!(Rad { full_turn: T::from(::std::f64::consts::PI * 2.0).unwrap() });
+implement_angle!(Deg { full_turn: T::from(360.0).unwrap() });
+
+impl<T: BaseFloat> From<Deg<T>> for Rad<T> {
+    #[inline]
+    fn from(deg: Deg<T>) -> Self {
+        Rad(deg.0.to_radians())
+    }
+}
+
+impl<T: BaseFloat> From<Rad<T>> for Deg<T> {
+    #[inline]
+    fn from(rad: Rad<T>) -> Self {
+        Deg(rad.0.to_degrees())
+    }
+}
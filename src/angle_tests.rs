@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use crate::angle::*;
+
+    use std::f32::consts::{PI,FRAC_PI_2};
+
+    type Radf = Rad<f32>;
+    type Degf = Deg<f32>;
+
+    const EPS: f32 = 4.0*f32::EPSILON;
+
+    // --------------------------------------------------------------------------
+    // Conversion
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn angle_deg_to_rad() {
+        assert!(Radf::from(Deg(180.0)).approx_eq(&Rad(PI), EPS, 2));
+        assert!(Radf::from(Deg(-90.0)).approx_eq(&Rad(-FRAC_PI_2), EPS, 2));
+    }
+
+    #[test]
+    fn angle_rad_to_deg() {
+        assert!(Degf::from(Rad(PI)).approx_eq(&Deg(180.0), EPS, 2));
+        let d: Degf = Rad(FRAC_PI_2).into();
+        assert!(d.approx_eq(&Deg(90.0), EPS, 2));
+    }
+
+    #[test]
+    fn angle_full_and_half_turn() {
+        assert_eq!(Degf::full_turn(), Deg(360.0));
+        assert_eq!(Degf::half_turn(), Deg(180.0));
+        assert!(Radf::full_turn().approx_eq(&Rad(2.0 * PI), EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Arithmetic
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn angle_arithmetic() {
+        assert_eq!(Deg(10.0) + Deg(20.0), Deg(30.0));
+        assert_eq!(Deg(10.0) - Deg(20.0), Deg(-10.0));
+        assert_eq!(Deg(10.0) * 3.0, Deg(30.0));
+        assert_eq!(3.0 * Deg(10.0), Deg(30.0));
+        assert_eq!(Deg(30.0) / 3.0, Deg(10.0));
+        assert_eq!(Deg(30.0) / Deg(10.0), 3.0);
+        assert_eq!(-Deg(30.0), Deg(-30.0));
+        assert_eq!(Deg(370.0) % Deg(360.0), Deg(10.0));
+    }
+
+    #[test]
+    fn angle_assign_arithmetic() {
+        let mut a = Rad(1.0);
+        a += Rad(1.0);
+        a *= 2.0;
+        a -= Rad(1.0);
+        a /= 3.0;
+        assert_eq!(a, Rad(1.0));
+    }
+
+    // --------------------------------------------------------------------------
+    // Trigonometry
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn angle_trigonometry() {
+        assert!(Deg(30.0f32).sin().approx_eq(&0.5, EPS, 2));
+        assert!(Deg(60.0f32).cos().approx_eq(&0.5, EPS, 2));
+        assert!(Deg(45.0f32).tan().approx_eq(&1.0, EPS, 2));
+        assert!(Rad(FRAC_PI_2).sin().approx_eq(&1.0, EPS, 2));
+        let (s, c) = Deg(90.0f32).sin_cos();
+        assert!(s.approx_eq(&1.0, EPS, 2) && c.abs() < EPS);
+    }
+
+    #[test]
+    fn angle_inverse_trigonometry() {
+        assert!(Degf::asin(0.5).approx_eq(&Deg(30.0), 1e-5, 2));
+        assert!(Degf::acos(0.5).approx_eq(&Deg(60.0), 1e-5, 2));
+        assert!(Degf::atan(1.0).approx_eq(&Deg(45.0), 1e-5, 2));
+        assert!(Radf::atan2(1.0, -1.0).approx_eq(&Rad(0.75 * PI), EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Wrapping
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn angle_normalize() {
+        assert_eq!(Deg(370.0f32).normalize(), Deg(10.0));
+        assert_eq!(Deg(-10.0f32).normalize(), Deg(350.0));
+        assert_eq!(Deg(360.0f32).normalize(), Deg(0.0));
+        assert_eq!(Deg(-720.0f32).normalize(), Deg(0.0));
+        assert!(Rad(-FRAC_PI_2).normalize().approx_eq(&Rad(1.5 * PI), EPS, 2));
+    }
+
+    #[test]
+    fn angle_normalize_signed() {
+        assert_eq!(Deg(190.0f32).normalize_signed(), Deg(-170.0));
+        assert_eq!(Deg(-190.0f32).normalize_signed(), Deg(170.0));
+        assert_eq!(Deg(180.0f32).normalize_signed(), Deg(-180.0));
+        assert_eq!(Deg(45.0f32).normalize_signed(), Deg(45.0));
+    }
+
+    #[test]
+    fn angle_shortest_difference() {
+        assert_eq!(Deg(350.0f32).shortest_difference(Deg(10.0)), Deg(20.0));
+        assert_eq!(Deg(10.0f32).shortest_difference(Deg(350.0)), Deg(-20.0));
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+};
+
+// Implementations of the hottest Vector4 and Matrix4 operations. Every Base type uses the scalar
+// versions below. With the `simd` feature f32 uses SSE2 versions on x86_64 instead. The f32 case is
+// picked by comparing type ids, which the compiler resolves statically, so Base does not have to
+// know about the backend and can still be implemented outside of this crate.
+//
+// The SSE2 dot product, matrix-vector product, matrix product and transposition add the terms in
+// the same order as the scalar versions and are therefore bit-identical to them. The SSE2 inverse
+// is computed with a different (cheaper) formulation. For well-conditioned matrices its elements
+// deviate from the scalar ones by at most 8 ULPs or 4 * f32::EPSILON (see backend_tests.rs).
+//
+// There is deliberately no AVX version. A Matrix4<f32> column fits an SSE2 register exactly, and
+// AVX would need either runtime feature detection on every call or a build for a specific CPU.
+
+#[inline]
+pub(crate) fn vector4_dot<T: Base>(a: Vector4<T>, b: Vector4<T>) -> T {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some((a, b)) = f32_dispatch::to_f32::<T, _, _>((a, b)) {
+        return f32_dispatch::from_f32::<T, _, _>(sse2::vector4_dot(a, b));
+    }
+    scalar::vector4_dot(a, b)
+}
+
+#[inline]
+pub(crate) fn matrix4_mul_vector4<T: Base>(m: Matrix4<T>, v: Vector4<T>) -> Vector4<T> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some((m, v)) = f32_dispatch::to_f32::<T, _, _>((m, v)) {
+        return f32_dispatch::from_f32::<T, _, _>(sse2::matrix4_mul_vector4(m, v));
+    }
+    scalar::matrix4_mul_vector4(m, v)
+}
+
+#[inline]
+pub(crate) fn matrix4_mul<T: Base>(a: Matrix4<T>, b: Matrix4<T>) -> Matrix4<T> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some((a, b)) = f32_dispatch::to_f32::<T, _, _>((a, b)) {
+        return f32_dispatch::from_f32::<T, _, _>(sse2::matrix4_mul(a, b));
+    }
+    scalar::matrix4_mul(a, b)
+}
+
+#[inline]
+pub(crate) fn matrix4_transpose<T: Base>(m: Matrix4<T>) -> Matrix4<T> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some(m) = f32_dispatch::to_f32::<T, _, _>(m) {
+        return f32_dispatch::from_f32::<T, _, _>(sse2::matrix4_transpose(m));
+    }
+    scalar::matrix4_transpose(m)
+}
+
+#[inline]
+pub(crate) fn matrix4_inverse<T: BaseFloat + ApproxEq<Flt=T>>(m: Matrix4<T>) -> Option<Matrix4<T>> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some(m) = f32_dispatch::to_f32::<T, _, _>(m) {
+        return f32_dispatch::from_f32::<T, _, _>(sse2::matrix4_inverse(m));
+    }
+    scalar::matrix4_inverse(m)
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+pub(crate) mod f32_dispatch {
+    use std::{
+        any::TypeId,
+        marker::PhantomData,
+        mem,
+    };
+
+    // TypeId::of requires 'static, which Base does not. Lifetimes do not take part in type ids, so
+    // the bound is sidestepped by calling TypeId::of through a trait object with an extended
+    // lifetime (the same trick as the typeid crate).
+    pub fn is_f32<T>() -> bool {
+        trait NonStaticAny {
+            fn type_id(&self) -> TypeId where Self: 'static;
+        }
+
+        impl<T> NonStaticAny for PhantomData<T> {
+            fn type_id(&self) -> TypeId where Self: 'static {
+                TypeId::of::<T>()
+            }
+        }
+
+        let phantom = PhantomData::<T>;
+        let any = unsafe { mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom) };
+        any.type_id() == TypeId::of::<f32>()
+    }
+
+    // Reinterprets a value of A as B if T is f32. B has to be A with T replaced by f32.
+    #[inline]
+    pub fn to_f32<T, A: Copy, B: Copy>(a: A) -> Option<B> {
+        if is_f32::<T>() {
+            assert_eq!(mem::size_of::<A>(), mem::size_of::<B>());
+            Some(unsafe { mem::transmute_copy(&a) })
+        } else {
+            None
+        }
+    }
+
+    // The inverse of to_f32. Only called with the result of an f32 kernel after to_f32 succeeded.
+    #[inline]
+    pub fn from_f32<T, B: Copy, A: Copy>(b: B) -> A {
+        debug_assert!(is_f32::<T>());
+        assert_eq!(mem::size_of::<A>(), mem::size_of::<B>());
+        unsafe { mem::transmute_copy(&b) }
+    }
+}
+
+// --------------------------------------------------------------------------
+// Scalar
+// --------------------------------------------------------------------------
+
+pub(crate) mod scalar {
+    use super::*;
+
+    #[inline]
+    pub fn vector4_dot<T: Base>(a: Vector4<T>, b: Vector4<T>) -> T {
+        let mut sum = T::zero();
+        sum += a.x * b.x;
+        sum += a.y * b.y;
+        sum += a.z * b.z;
+        sum += a.w * b.w;
+        sum
+    }
+
+    #[inline]
+    pub fn matrix4_mul_vector4<T: Base>(m: Matrix4<T>, v: Vector4<T>) -> Vector4<T> {
+        Vector4::new(
+            vector4_dot(m.row(0), v),
+            vector4_dot(m.row(1), v),
+            vector4_dot(m.row(2), v),
+            vector4_dot(m.row(3), v)
+        )
+    }
+
+    #[inline]
+    pub fn matrix4_mul<T: Base>(a: Matrix4<T>, b: Matrix4<T>) -> Matrix4<T> {
+        Matrix4::from_cols(
+            matrix4_mul_vector4(a, b.col(0)),
+            matrix4_mul_vector4(a, b.col(1)),
+            matrix4_mul_vector4(a, b.col(2)),
+            matrix4_mul_vector4(a, b.col(3))
+        )
+    }
+
+    #[inline]
+    pub fn matrix4_transpose<T: Base>(m: Matrix4<T>) -> Matrix4<T> {
+        Matrix4::from_cols(m.row(0), m.row(1), m.row(2), m.row(3))
+    }
+
+    pub fn matrix4_inverse<T: BaseFloat + ApproxEq<Flt=T>>(m: Matrix4<T>) -> Option<Matrix4<T>> {
+        let (m00, m01, m02, m03) = (m.row_elem(0, 0), m.row_elem(0, 1), m.row_elem(0, 2), m.row_elem(0, 3));
+        let (m10, m11, m12, m13) = (m.row_elem(1, 0), m.row_elem(1, 1), m.row_elem(1, 2), m.row_elem(1, 3));
+        let (m20, m21, m22, m23) = (m.row_elem(2, 0), m.row_elem(2, 1), m.row_elem(2, 2), m.row_elem(2, 3));
+        let (m30, m31, m32, m33) = (m.row_elem(3, 0), m.row_elem(3, 1), m.row_elem(3, 2), m.row_elem(3, 3));
+
+        let d = T::one() / m.det();
+
+        Some(Matrix4::from_components_row_major(
+            d*(m11*m22*m33 + m12*m23*m31 + m13*m21*m32 - m11*m23*m32 - m12*m21*m33 - m13*m22*m31),
+            d*(m01*m23*m32 + m02*m21*m33 + m03*m22*m31 - m01*m22*m33 - m02*m23*m31 - m03*m21*m32),
+            d*(m01*m12*m33 + m02*m13*m31 + m03*m11*m32 - m01*m13*m32 - m02*m11*m33 - m03*m12*m31),
+            d*(m01*m13*m22 + m02*m11*m23 + m03*m12*m21 - m01*m12*m23 - m02*m13*m21 - m03*m11*m22),
+            d*(m10*m23*m32 + m12*m20*m33 + m13*m22*m30 - m10*m22*m33 - m12*m23*m30 - m13*m20*m32),
+            d*(m00*m22*m33 + m02*m23*m30 + m03*m20*m32 - m00*m23*m32 - m02*m20*m33 - m03*m22*m30),
+            d*(m00*m13*m32 + m02*m10*m33 + m03*m12*m30 - m00*m12*m33 - m02*m13*m30 - m03*m10*m32),
+            d*(m00*m12*m23 + m02*m13*m20 + m03*m10*m22 - m00*m13*m22 - m02*m10*m23 - m03*m12*m20),
+            d*(m10*m21*m33 + m11*m23*m30 + m13*m20*m31 - m10*m23*m31 - m11*m20*m33 - m13*m21*m30),
+            d*(m00*m23*m31 + m01*m20*m33 + m03*m21*m30 - m00*m21*m33 - m01*m23*m30 - m03*m20*m31),
+            d*(m00*m11*m33 + m01*m13*m30 + m03*m10*m31 - m00*m13*m31 - m01*m10*m33 - m03*m11*m30),
+            d*(m00*m13*m21 + m01*m10*m23 + m03*m11*m20 - m00*m11*m23 - m01*m13*m20 - m03*m10*m21),
+            d*(m10*m22*m31 + m11*m20*m32 + m12*m21*m30 - m10*m21*m32 - m11*m22*m30 - m12*m20*m31),
+            d*(m00*m21*m32 + m01*m22*m30 + m02*m20*m31 - m00*m22*m31 - m01*m20*m32 - m02*m21*m30),
+            d*(m00*m12*m31 + m01*m10*m32 + m02*m11*m30 - m00*m11*m32 - m01*m12*m30 - m02*m10*m31),
+            d*(m00*m11*m22 + m01*m12*m20 + m02*m10*m21 - m00*m12*m21 - m01*m10*m22 - m02*m11*m20)
+        ))
+    }
+}
+
+// --------------------------------------------------------------------------
+// SSE2
+// --------------------------------------------------------------------------
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+pub(crate) mod sse2 {
+    use std::arch::x86_64::*;
+
+    use super::*;
+
+    // Shuffle masks, named by the source lanes of the result from first to last.
+    const XXXX: i32 = 0b00_00_00_00;
+    const YYYY: i32 = 0b01_01_01_01;
+    const ZZZZ: i32 = 0b10_10_10_10;
+    const WWWW: i32 = 0b11_11_11_11;
+    const YZXW: i32 = 0b11_00_10_01;
+
+    #[inline]
+    fn load_vector4(v: Vector4<f32>) -> __m128 {
+        unsafe { _mm_setr_ps(v.x, v.y, v.z, v.w) }
+    }
+
+    #[inline]
+    fn store_vector4(r: __m128) -> Vector4<f32> {
+        let mut a = [0.0; 4];
+        unsafe { _mm_storeu_ps(a.as_mut_ptr(), r) };
+        Vector4::new(a[0], a[1], a[2], a[3])
+    }
+
+    // Matrix4 is #[repr(C)] and stores its elements column by column.
+    #[inline]
+    fn load_cols(m: &Matrix4<f32>) -> [__m128; 4] {
+        let p = m as *const Matrix4<f32> as *const f32;
+        unsafe { [_mm_loadu_ps(p), _mm_loadu_ps(p.add(4)), _mm_loadu_ps(p.add(8)), _mm_loadu_ps(p.add(12))] }
+    }
+
+    #[inline]
+    fn store_cols(cols: [__m128; 4]) -> Matrix4<f32> {
+        let mut m = Matrix4::zero();
+        let p = &mut m as *mut Matrix4<f32> as *mut f32;
+        unsafe {
+            _mm_storeu_ps(p, cols[0]);
+            _mm_storeu_ps(p.add(4), cols[1]);
+            _mm_storeu_ps(p.add(8), cols[2]);
+            _mm_storeu_ps(p.add(12), cols[3]);
+        }
+        m
+    }
+
+    // Sums the lanes in order, starting from zero like the scalar version.
+    #[inline]
+    fn sum_lanes(p: __m128) -> f32 {
+        unsafe {
+            let mut sum = _mm_add_ss(_mm_setzero_ps(), p);
+            sum = _mm_add_ss(sum, _mm_shuffle_ps(p, p, YYYY));
+            sum = _mm_add_ss(sum, _mm_movehl_ps(p, p));
+            sum = _mm_add_ss(sum, _mm_shuffle_ps(p, p, WWWW));
+            _mm_cvtss_f32(sum)
+        }
+    }
+
+    // Linear combination of the columns, equivalent to the row-wise dot products of the scalar
+    // version.
+    #[inline]
+    fn mul_cols(cols: &[__m128; 4], v: __m128) -> __m128 {
+        unsafe {
+            let mut r = _mm_setzero_ps();
+            r = _mm_add_ps(r, _mm_mul_ps(cols[0], _mm_shuffle_ps(v, v, XXXX)));
+            r = _mm_add_ps(r, _mm_mul_ps(cols[1], _mm_shuffle_ps(v, v, YYYY)));
+            r = _mm_add_ps(r, _mm_mul_ps(cols[2], _mm_shuffle_ps(v, v, ZZZZ)));
+            _mm_add_ps(r, _mm_mul_ps(cols[3], _mm_shuffle_ps(v, v, WWWW)))
+        }
+    }
+
+    #[inline]
+    fn transpose(c: [__m128; 4]) -> [__m128; 4] {
+        unsafe {
+            let t0 = _mm_unpacklo_ps(c[0], c[1]);
+            let t1 = _mm_unpacklo_ps(c[2], c[3]);
+            let t2 = _mm_unpackhi_ps(c[0], c[1]);
+            let t3 = _mm_unpackhi_ps(c[2], c[3]);
+            [_mm_movelh_ps(t0, t1), _mm_movehl_ps(t1, t0), _mm_movelh_ps(t2, t3), _mm_movehl_ps(t3, t2)]
+        }
+    }
+
+    // Cross product of the first three lanes. The last lane of the result is zero for finite input.
+    #[inline]
+    fn cross(a: __m128, b: __m128) -> __m128 {
+        unsafe {
+            let a_yzx = _mm_shuffle_ps(a, a, YZXW);
+            let b_yzx = _mm_shuffle_ps(b, b, YZXW);
+            let c = _mm_sub_ps(_mm_mul_ps(a, b_yzx), _mm_mul_ps(a_yzx, b));
+            _mm_shuffle_ps(c, c, YZXW)
+        }
+    }
+
+    #[inline]
+    fn splat(v: __m128, lane: i32) -> __m128 {
+        unsafe {
+            match lane {
+                0 => _mm_shuffle_ps(v, v, XXXX),
+                1 => _mm_shuffle_ps(v, v, YYYY),
+                2 => _mm_shuffle_ps(v, v, ZZZZ),
+                _ => _mm_shuffle_ps(v, v, WWWW),
+            }
+        }
+    }
+
+    pub fn vector4_dot(a: Vector4<f32>, b: Vector4<f32>) -> f32 {
+        sum_lanes(unsafe { _mm_mul_ps(load_vector4(a), load_vector4(b)) })
+    }
+
+    pub fn matrix4_mul_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+        store_vector4(mul_cols(&load_cols(&m), load_vector4(v)))
+    }
+
+    pub fn matrix4_mul(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+        let a = load_cols(&a);
+        let b = load_cols(&b);
+        store_cols([mul_cols(&a, b[0]), mul_cols(&a, b[1]), mul_cols(&a, b[2]), mul_cols(&a, b[3])])
+    }
+
+    pub fn matrix4_transpose(m: Matrix4<f32>) -> Matrix4<f32> {
+        store_cols(transpose(load_cols(&m)))
+    }
+
+    // The columns are split into their upper three elements a, b, c, d and the elements of the
+    // last row x, y, z, w. The inverse is then built from cross products of these (see Lengyel,
+    // Foundations of Game Engine Development, Volume 1, Section 1.7.5).
+    pub fn matrix4_inverse(m: Matrix4<f32>) -> Option<Matrix4<f32>> {
+        let [a, b, c, d] = load_cols(&m);
+        let (x, y, z, w) = (splat(a, 3), splat(b, 3), splat(c, 3), splat(d, 3));
+        unsafe {
+            let s = cross(a, b);
+            let t = cross(c, d);
+            let u = _mm_sub_ps(_mm_mul_ps(a, y), _mm_mul_ps(b, x));
+            let v = _mm_sub_ps(_mm_mul_ps(c, w), _mm_mul_ps(d, z));
+
+            let det = sum_lanes(_mm_add_ps(_mm_mul_ps(s, v), _mm_mul_ps(t, u)));
+            let inv_det = _mm_set1_ps(1.0 / det);
+            let (s, t, u, v) = (_mm_mul_ps(s, inv_det), _mm_mul_ps(t, inv_det), _mm_mul_ps(u, inv_det), _mm_mul_ps(v, inv_det));
+
+            let r0 = _mm_add_ps(cross(b, v), _mm_mul_ps(t, y));
+            let r1 = _mm_sub_ps(cross(v, a), _mm_mul_ps(t, x));
+            let r2 = _mm_add_ps(cross(d, u), _mm_mul_ps(s, w));
+            let r3 = _mm_sub_ps(cross(u, c), _mm_mul_ps(s, z));
+
+            // The last lane of s and t is zero, so these are the dot products of the upper three
+            // elements.
+            let dot = |p: __m128, q: __m128| sum_lanes(_mm_mul_ps(p, q));
+            let (r0, r1, r2, r3) = (store_vector4(r0), store_vector4(r1), store_vector4(r2), store_vector4(r3));
+            Some(Matrix4::from_rows(
+                Vector4::new(r0.x, r0.y, r0.z, -dot(b, t)),
+                Vector4::new(r1.x, r1.y, r1.z,  dot(a, t)),
+                Vector4::new(r2.x, r2.y, r2.z, -dot(d, s)),
+                Vector4::new(r3.x, r3.y, r3.z,  dot(c, s))
+            ))
+        }
+    }
+}
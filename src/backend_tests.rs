@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use crate::backend::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+    use crate::traits::*;
+
+    type Mat4 = Matrix4<f32>;
+
+    type Vec4 = Vector4<f32>;
+
+    // The operators dispatch to the SSE2 implementations when the `simd` feature is enabled, so
+    // these tests compare both paths in that case.
+
+    // Deterministic values in [-1, 1).
+    struct Lcg(u32);
+
+    impl Lcg {
+        fn next(&mut self) -> f32 {
+            self.0 = self.0.wrapping_mul(1664525).wrapping_add(1013904223);
+            (self.0 >> 8) as f32 / (1 << 23) as f32 - 1.0
+        }
+
+        fn vector(&mut self) -> Vec4 {
+            Vec4::new(self.next(), self.next(), self.next(), self.next())
+        }
+
+        fn matrix(&mut self) -> Mat4 {
+            Mat4::from_cols(self.vector(), self.vector(), self.vector(), self.vector())
+        }
+    }
+
+    fn bits(m: Mat4) -> [u32; 16] {
+        let mut res = [0; 16];
+        for (r, e) in res.iter_mut().zip(m.to_array().iter()) {
+            *r = e.to_bits();
+        }
+        res
+    }
+
+    #[test]
+    fn backend_vector4_dot() {
+        let mut rng = Lcg(1);
+        for _ in 0..1000 {
+            let (a, b) = (rng.vector(), rng.vector());
+            assert_eq!(a.dot(b).to_bits(), scalar::vector4_dot(a, b).to_bits());
+        }
+    }
+
+    #[test]
+    fn backend_matrix4_mul_vector4() {
+        let mut rng = Lcg(2);
+        for _ in 0..1000 {
+            let (m, v) = (rng.matrix(), rng.vector());
+            let (a, b) = (m * v, scalar::matrix4_mul_vector4(m, v));
+            assert_eq!([a.x, a.y, a.z, a.w].map(f32::to_bits), [b.x, b.y, b.z, b.w].map(f32::to_bits));
+        }
+    }
+
+    #[test]
+    fn backend_matrix4_mul() {
+        let mut rng = Lcg(3);
+        for _ in 0..1000 {
+            let (a, b) = (rng.matrix(), rng.matrix());
+            assert_eq!(bits(a * b), bits(scalar::matrix4_mul(a, b)));
+        }
+    }
+
+    #[test]
+    fn backend_matrix4_transpose() {
+        let mut rng = Lcg(4);
+        let m = rng.matrix();
+        assert_eq!(bits(m.transpose()), bits(scalar::matrix4_transpose(m)));
+        assert_eq!(m.transpose().row(2), m.col(2));
+    }
+
+    #[test]
+    fn backend_matrix4_inverse() {
+        let mut rng = Lcg(5);
+        for _ in 0..1000 {
+            // Keep the matrices well-conditioned by making them diagonally dominant.
+            let mut m = rng.matrix();
+            for i in 0..4 {
+                m.set_col_elem(i, i, m.col_elem(i, i) + 4.0);
+            }
+            let (a, b) = (m.inverse().unwrap(), scalar::matrix4_inverse(m).unwrap());
+            assert!(a.approx_eq(&b, 4.0 * f32::EPSILON, 8), "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    fn backend_f32_dispatch() {
+        assert!(f32_dispatch::is_f32::<f32>());
+        assert!(!f32_dispatch::is_f32::<f64>());
+        assert!(!f32_dispatch::is_f32::<i32>());
+        assert!(!f32_dispatch::is_f32::<u32>());
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(f32_dispatch::to_f32::<f32, Vec4, Vec4>(v), Some(v));
+        assert_eq!(f32_dispatch::to_f32::<i32, Vector4<i32>, Vec4>(Vector4::new(1, 2, 3, 4)), None);
+    }
+
+    #[test]
+    fn backend_integer_matrix4() {
+        let a = Matrix4::from_components_row_major(
+            1, 2, 3, 4,
+            5, 6, 7, 8,
+            9, 10, 11, 12,
+            13, 14, 15, 16
+        );
+        assert_eq!(a * Matrix4::identity(), a);
+        assert_eq!(a.transpose().row(0), Vector4::new(1, 5, 9, 13));
+    }
+}
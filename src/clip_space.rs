@@ -0,0 +1,142 @@
+use crate::{
+    traits::*,
+    matrix::*,
+    angle::*,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    // OpenGL
+    NegativeOneToOne,
+    // Direct3D, Vulkan, Metal, wgpu
+    ZeroToOne,
+}
+
+impl DepthRange {
+    // The lower and upper bound of NDC depth.
+    pub fn bounds<T: BaseFloat>(&self) -> (T, T) {
+        match self {
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    // The camera looks down -z.
+    Right,
+    // The camera looks down +z.
+    Left,
+}
+
+// Describes the clip space the projection matrices map to. With reverse_z the near plane is mapped
+// to the far end of the depth range and vice versa, which spreads floating-point depth precision
+// more evenly.
+//
+// The y axis of clip space always points up. APIs whose framebuffer y points down (e.g. Vulkan)
+// flip it in the viewport.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClipSpace {
+    pub depth_range: DepthRange,
+    pub handedness: Handedness,
+    pub reverse_z: bool,
+}
+
+impl ClipSpace {
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth_range: DepthRange::NegativeOneToOne,
+        handedness: Handedness::Right,
+        reverse_z: false,
+    };
+
+    pub const DIRECTX: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        handedness: Handedness::Left,
+        reverse_z: false,
+    };
+
+    pub const VULKAN: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        handedness: Handedness::Right,
+        reverse_z: false,
+    };
+
+    pub fn with_reverse_z(self) -> Self {
+        ClipSpace { reverse_z: true, ..self }
+    }
+
+    // The NDC depth values of the near and the far plane.
+    pub fn depth_near_far<T: BaseFloat>(&self) -> (T, T) {
+        let (lo, hi) = self.depth_range.bounds();
+        if self.reverse_z { (hi, lo) } else { (lo, hi) }
+    }
+
+    // The sign of view space z in front of the camera.
+    fn forward<T: BaseFloat>(&self) -> T {
+        match self.handedness {
+            Handedness::Right => -T::one(),
+            Handedness::Left => T::one(),
+        }
+    }
+}
+
+impl Default for ClipSpace {
+    fn default() -> Self {
+        ClipSpace::OPENGL
+    }
+}
+
+impl<T: BaseFloat> Matrix4<T> {
+    // z_near and z_far are positive distances in front of the camera. z_far may be T::infinity().
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_perspective_from_bounds_with_clip_space(left: T, right: T, bottom: T, top: T, z_near: T, z_far: T, clip: ClipSpace) -> Self {
+        let two = T::one() + T::one();
+        let h = clip.forward::<T>();
+        let w = right - left;
+        let ht = top - bottom;
+        let (d_near, d_far) = clip.depth_near_far::<T>();
+        // NDC depth is a + b / distance
+        let (a, b) = if z_far.is_infinite() {
+            (d_far, (d_near - d_far) * z_near)
+        } else {
+            let d = z_far - z_near;
+            ((d_far * z_far - d_near * z_near) / d, (d_near - d_far) * z_near * z_far / d)
+        };
+        Matrix4::from_components_row_major(
+            two * z_near / w, T::zero()        , -h * (right + left) / w , T::zero(),
+            T::zero()       , two * z_near / ht, -h * (top + bottom) / ht, T::zero(),
+            T::zero()       , T::zero()        , h * a                   , b,
+            T::zero()       , T::zero()        , h                       , T::zero()
+        )
+    }
+
+    // fov is the horizontal field of view in degrees.
+    pub fn new_perspective_from_fov_with_clip_space(fov: T, aspect: T, z_near: T, z_far: T, clip: ClipSpace) -> Self {
+        Self::new_perspective_from_fov_angle_with_clip_space(Deg(fov), aspect, z_near, z_far, clip)
+    }
+
+    pub fn new_perspective_from_fov_angle_with_clip_space<A: Into<Rad<T>>>(fov: A, aspect: T, z_near: T, z_far: T, clip: ClipSpace) -> Self {
+        let two = T::one() + T::one();
+        let right = z_near * (fov.into() / two).tan();
+        let top = right / aspect;
+        Self::new_perspective_from_bounds_with_clip_space(-right, right, -top, top, z_near, z_far, clip)
+    }
+
+    // z_near and z_far are distances in front of the camera. z_far has to be finite.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_orthographic_from_bounds_with_clip_space(left: T, right: T, bottom: T, top: T, z_near: T, z_far: T, clip: ClipSpace) -> Self {
+        let two = T::one() + T::one();
+        let h = clip.forward::<T>();
+        let w = right - left;
+        let ht = top - bottom;
+        let (d_near, d_far) = clip.depth_near_far::<T>();
+        let a = (d_far - d_near) / (z_far - z_near);
+        Matrix4::from_components_row_major(
+            two / w  , T::zero(), T::zero(), -(right + left) / w,
+            T::zero(), two / ht , T::zero(), -(top + bottom) / ht,
+            T::zero(), T::zero(), h * a    , d_near - a * z_near,
+            T::zero(), T::zero(), T::zero(), T::one()
+        )
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use crate::clip_space::*;
+    use crate::geometry::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+
+    type Mat4 = Matrix4<f32>;
+
+    type Vec3 = Vector3<f32>;
+    type Vec4 = Vector4<f32>;
+
+    const NEAR: f32 = 0.5;
+    const FAR: f32 = 100.0;
+
+    // The NDC depth of a point at the given distance in front of the camera.
+    fn ndc_depth(m: &Mat4, clip: ClipSpace, distance: f32) -> f32 {
+        let z = match clip.handedness {
+            Handedness::Right => -distance,
+            Handedness::Left => distance,
+        };
+        (m * Vec4::new(0.0, 0.0, z, 1.0)).wdiv().z
+    }
+
+    fn all_clip_spaces() -> Vec<ClipSpace> {
+        let mut res = vec![];
+        for depth_range in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+            for handedness in [Handedness::Right, Handedness::Left] {
+                for reverse_z in [false, true] {
+                    res.push(ClipSpace { depth_range, handedness, reverse_z });
+                }
+            }
+        }
+        res
+    }
+
+    // --------------------------------------------------------------------------
+    // ClipSpace
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn clip_space_depth_near_far() {
+        assert_eq!(ClipSpace::OPENGL.depth_near_far::<f32>(), (-1.0, 1.0));
+        assert_eq!(ClipSpace::VULKAN.depth_near_far::<f32>(), (0.0, 1.0));
+        assert_eq!(ClipSpace::VULKAN.with_reverse_z().depth_near_far::<f32>(), (1.0, 0.0));
+        assert_eq!(ClipSpace::default(), ClipSpace::OPENGL);
+    }
+
+    // --------------------------------------------------------------------------
+    // Perspective
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn perspective_near_far() {
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_perspective_from_fov_with_clip_space(90.0, 1.5, NEAR, FAR, clip);
+            let (d_near, d_far) = clip.depth_near_far::<f32>();
+            assert!(ndc_depth(&m, clip, NEAR).approx_eq(&d_near, 1e-5, 4), "{:?}", clip);
+            assert!(ndc_depth(&m, clip, FAR).approx_eq(&d_far, 1e-5, 4), "{:?}", clip);
+            // Depth is monotonic in between
+            let mid = ndc_depth(&m, clip, 10.0);
+            assert!((mid - d_near) * (d_far - mid) > 0.0, "{:?}", clip);
+        }
+    }
+
+    #[test]
+    fn perspective_infinite_far() {
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_perspective_from_fov_with_clip_space(90.0, 1.5, NEAR, f32::INFINITY, clip);
+            let (d_near, d_far) = clip.depth_near_far::<f32>();
+            assert!(ndc_depth(&m, clip, NEAR).approx_eq(&d_near, 1e-5, 4), "{:?}", clip);
+            assert!(ndc_depth(&m, clip, 1e7).approx_eq(&d_far, 1e-5, 4), "{:?}", clip);
+        }
+    }
+
+    #[test]
+    fn perspective_xy() {
+        // Points on the frustum edges map to the NDC borders regardless of handedness
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_perspective_from_bounds_with_clip_space(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0, clip);
+            let z = if clip.handedness == Handedness::Right { -2.0 } else { 2.0 };
+            let p = (m * Vec4::new(6.0, -4.0, z, 1.0)).wdiv();
+            assert!(p.x.approx_eq(&1.0, 1e-6, 4) && p.y.approx_eq(&-1.0, 1e-6, 4), "{:?}", clip);
+        }
+    }
+
+    #[test]
+    fn perspective_opengl_matches_default() {
+        let a = Mat4::new_perspective_from_bounds(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0);
+        let b = Mat4::new_perspective_from_bounds_with_clip_space(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0, ClipSpace::OPENGL);
+        assert!(a.approx_eq(&b, 4.0 * f32::EPSILON, 4));
+        let a = Mat4::new_perspective_from_fov(75.0, 1.5, NEAR, FAR);
+        let b = Mat4::new_perspective_from_fov_with_clip_space(75.0, 1.5, NEAR, FAR, ClipSpace::OPENGL);
+        assert!(a.approx_eq(&b, 4.0 * f32::EPSILON, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // Orthographic
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn orthographic_near_far() {
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_orthographic_from_bounds_with_clip_space(-2.0, 2.0, -1.0, 1.0, NEAR, FAR, clip);
+            let (d_near, d_far) = clip.depth_near_far::<f32>();
+            assert!(ndc_depth(&m, clip, NEAR).approx_eq(&d_near, 1e-6, 4), "{:?}", clip);
+            assert!(ndc_depth(&m, clip, FAR).approx_eq(&d_far, 1e-6, 4), "{:?}", clip);
+        }
+    }
+
+    #[test]
+    fn orthographic_opengl_matches_default() {
+        let a = Mat4::new_orthographic_from_bounds(-2.0, 4.0, -1.0, 3.0, NEAR, FAR);
+        let b = Mat4::new_orthographic_from_bounds_with_clip_space(-2.0, 4.0, -1.0, 3.0, NEAR, FAR, ClipSpace::OPENGL);
+        assert!(a.approx_eq(&b, 4.0 * f32::EPSILON, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // Frustum
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn frustum_with_clip_space() {
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_perspective_from_fov_with_clip_space(90.0, 1.0, 1.0, 10.0, clip);
+            let f = Frustum::from_matrix_with_clip_space(&m, clip);
+            let forward = if clip.handedness == Handedness::Right { -1.0 } else { 1.0 };
+            assert!(f.far.is_some(), "{:?}", clip);
+            let corners = f.corners().unwrap();
+            assert!(corners[0].approx_eq(&Vec3::new(-1.0, -1.0, forward), 1e-5, 4), "{:?}", clip);
+            assert!(corners[7].approx_eq(&Vec3::new(10.0, 10.0, 10.0 * forward), 1e-5, 4), "{:?}", clip);
+            assert_eq!(f.test_point(Vec3::new(0.0, 0.0, 5.0 * forward)), Containment::Inside);
+            assert_eq!(f.test_point(Vec3::new(0.0, 0.0, 0.5 * forward)), Containment::Outside);
+            assert_eq!(f.test_point(Vec3::new(0.0, 0.0, 11.0 * forward)), Containment::Outside);
+        }
+    }
+
+    #[test]
+    fn frustum_infinite_far() {
+        for clip in all_clip_spaces() {
+            let m = Mat4::new_perspective_from_fov_with_clip_space(90.0, 1.0, 1.0, f32::INFINITY, clip);
+            let f = Frustum::from_matrix_with_clip_space(&m, clip);
+            let forward = if clip.handedness == Handedness::Right { -1.0 } else { 1.0 };
+            assert!(f.far.is_none(), "{:?}: {:?}", clip, f.far);
+            assert!(f.near.normal.approx_eq(&Vec3::new(0.0, 0.0, forward), 1e-6, 4), "{:?}", clip);
+            assert_eq!(f.test_point(Vec3::new(0.0, 0.0, 1e6 * forward)), Containment::Inside);
+            assert_eq!(f.test_sphere(Vec3::new(0.0, 0.0, 0.0), 0.5), Containment::Outside);
+            assert!(f.corners().is_none(), "{:?}", clip);
+        }
+    }
+}
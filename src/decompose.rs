@@ -0,0 +1,83 @@
+use std::{error, fmt};
+
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    quaternion::*,
+};
+
+// Translation, rotation and scale.
+pub type Trs<T> = (Vector3<T>, Quaternion<T>, Vector3<T>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecomposeError {
+    // The last row is not (0, 0, 0, 1).
+    Projective,
+    // At least one of the axes has been scaled to zero.
+    Singular,
+    // The axes are not orthogonal after removing the scale.
+    Shear,
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecomposeError::Projective => write!(f, "matrix contains a projective part"),
+            DecomposeError::Singular => write!(f, "matrix has a zero scale"),
+            DecomposeError::Shear => write!(f, "matrix contains shear"),
+        }
+    }
+}
+
+impl error::Error for DecomposeError {}
+
+impl<T: BaseFloat> Matrix4<T> {
+    // Equivalent to new_translation_from_vector(translation) * Matrix4::from(rotation) * new_scale_from_vector(scale).
+    pub fn from_trs(translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T>) -> Self {
+        let r = Matrix3::from(rotation);
+        Matrix4::from_components_row_major(
+            r.m00 * scale.x, r.m01 * scale.y, r.m02 * scale.z, translation.x,
+            r.m10 * scale.x, r.m11 * scale.y, r.m12 * scale.z, translation.y,
+            r.m20 * scale.x, r.m21 * scale.y, r.m22 * scale.z, translation.z,
+            T::zero()      , T::zero()      , T::zero()      , T::one()
+        )
+    }
+
+    // Splits the matrix into translation, rotation and scale so that from_trs reproduces it.
+    // Mirroring transformations (negative determinant) are expressed by a negative x scale.
+    // Deviations up to the square root of the machine epsilon are tolerated, for the scale relative
+    // to the largest scale.
+    pub fn decompose(&self) -> Result<Trs<T>, DecomposeError> {
+        let tolerance = T::epsilon().sqrt();
+        let near = |a: T, b: T| T::abs(a - b) <= tolerance;
+        if !(near(self.m30, T::zero()) && near(self.m31, T::zero()) && near(self.m32, T::zero()) && near(self.m33, T::one())) {
+            return Err(DecomposeError::Projective);
+        }
+
+        let translation = Vector3::new(self.m03, self.m13, self.m23);
+
+        let c0 = Vector3::new(self.m00, self.m10, self.m20);
+        let c1 = Vector3::new(self.m01, self.m11, self.m21);
+        let c2 = Vector3::new(self.m02, self.m12, self.m22);
+        let mut scale = Vector3::new(c0.length(), c1.length(), c2.length());
+        // Relative to the largest scale, so that uniformly small scales are fine
+        let max_scale = scale.x.max(scale.y).max(scale.z);
+        let min_scale = scale.x.min(scale.y).min(scale.z);
+        if min_scale <= tolerance * max_scale {
+            return Err(DecomposeError::Singular);
+        }
+
+        if c0.cross(c1).dot(c2) < T::zero() {
+            scale.x = -scale.x;
+        }
+
+        let (r0, r1, r2) = (c0 / scale.x, c1 / scale.y, c2 / scale.z);
+        if T::abs(r0.dot(r1)) > tolerance || T::abs(r0.dot(r2)) > tolerance || T::abs(r1.dot(r2)) > tolerance {
+            return Err(DecomposeError::Shear);
+        }
+
+        let rotation = Quaternion::from(Matrix3::from_cols(r0, r1, r2)).normalize();
+        Ok((translation, rotation, scale))
+    }
+}
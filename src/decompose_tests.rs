@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::decompose::*;
+    use crate::quaternion::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+
+    type Quat = Quaternion<f32>;
+
+    type Mat4 = Matrix4<f32>;
+
+    type Vec3 = Vector3<f32>;
+
+    const EPS: f32 = 1e-5;
+
+    #[test]
+    fn matrix4_from_trs() {
+        let axis = Vec3::new(0.0, 0.6, 0.8);
+        let m = Mat4::from_trs(Vec3::new(1.0, 2.0, 3.0), Quat::from_axis_angle(axis, 0.7), Vec3::new(2.0, 3.0, 4.0));
+        let r = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation(axis, 0.7) * Mat4::new_scale(2.0, 3.0, 4.0);
+        assert!(m.approx_eq(&r, EPS, 4));
+    }
+
+    #[test]
+    fn matrix4_decompose() {
+        let m = Mat4::new_translation(1.0, -2.0, 3.0) * Mat4::new_rotation_y(1.2) * Mat4::new_scale(2.0, 0.5, 3.0);
+        let (t, r, s) = m.decompose().unwrap();
+        assert!(t.approx_eq(&Vec3::new(1.0, -2.0, 3.0), EPS, 4));
+        assert!(r.approx_eq(&Quat::from_rotation_y(1.2), EPS, 4));
+        assert!(s.approx_eq(&Vec3::new(2.0, 0.5, 3.0), EPS, 4));
+    }
+
+    #[test]
+    fn matrix4_decompose_round_trip() {
+        let m = Mat4::new_translation(4.0, 5.0, 6.0)
+            * Mat4::new_rotation_x(0.3) * Mat4::new_rotation_z(-2.0)
+            * Mat4::new_scale(1.5, 1.5, 0.25);
+        let (t, r, s) = m.decompose().unwrap();
+        assert!(Mat4::from_trs(t, r, s).approx_eq(&m, EPS, 4));
+    }
+
+    #[test]
+    fn matrix4_decompose_identity() {
+        let (t, r, s) = Mat4::identity().decompose().unwrap();
+        assert_eq!(t, Vec3::zero());
+        assert!(r.approx_eq(&Quat::identity(), EPS, 4));
+        assert_eq!(s, Vec3::one());
+    }
+
+    #[test]
+    fn matrix4_decompose_mirror() {
+        let m = Mat4::new_translation(1.0, 0.0, 0.0) * Mat4::new_rotation_z(0.5) * Mat4::new_scale(1.0, -2.0, 1.0);
+        assert!(m.det() < 0.0);
+        let (t, r, s) = m.decompose().unwrap();
+        assert!(s.x < 0.0 && s.y > 0.0 && s.z > 0.0);
+        assert!(Mat4::from_trs(t, r, s).approx_eq(&m, EPS, 4));
+    }
+
+    #[test]
+    fn matrix4_decompose_shear() {
+        let shear = Mat4::from_components_row_major(
+            1.0, 0.5, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0
+        );
+        assert_eq!(shear.decompose(), Err(DecomposeError::Shear));
+        // Non-uniform scale applied after a rotation introduces shear as well.
+        let m = Mat4::new_scale(1.0, 3.0, 1.0) * Mat4::new_rotation_z(0.5);
+        assert_eq!(m.decompose(), Err(DecomposeError::Shear));
+    }
+
+    #[test]
+    fn matrix4_decompose_singular() {
+        assert_eq!(Mat4::new_scale(1.0, 0.0, 1.0).decompose(), Err(DecomposeError::Singular));
+        assert_eq!(Mat4::new_scale(1.0, 1e-4, 1.0).decompose(), Err(DecomposeError::Singular));
+        assert_eq!(Mat4::new_translation(1.0, 2.0, 3.0).decompose().map(|trs| trs.2), Ok(Vec3::one()));
+        assert_eq!(Mat4::new_scale(0.0, 0.0, 0.0).decompose(), Err(DecomposeError::Singular));
+    }
+
+    #[test]
+    fn matrix4_decompose_small_scale() {
+        let axis = Vec3::new(0.0, 0.6, 0.8);
+        let m = Mat4::from_trs(Vec3::new(1.0, 2.0, 3.0), Quat::from_axis_angle(axis, 0.7), Vec3::new(1e-4, 1e-4, 1e-4));
+        let (t, r, s) = m.decompose().unwrap();
+        assert!(t.approx_eq(&Vec3::new(1.0, 2.0, 3.0), EPS, 4));
+        assert!(r.approx_eq(&Quat::from_axis_angle(axis, 0.7), EPS, 4));
+        assert!(s.approx_eq(&Vec3::new(1e-4, 1e-4, 1e-4), 1e-9, 4));
+        let m = Mat4::from_trs(Vec3::zero(), Quat::identity(), Vec3::new(1e-4, 2e-4, 3e-4));
+        assert!(m.decompose().unwrap().2.approx_eq(&Vec3::new(1e-4, 2e-4, 3e-4), 1e-9, 4));
+    }
+
+    #[test]
+    fn matrix4_decompose_projective() {
+        let m = Mat4::new_perspective_from_bounds(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+        assert_eq!(m.decompose(), Err(DecomposeError::Projective));
+    }
+}
@@ -0,0 +1,243 @@
+use std::ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Neg};
+
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    quaternion::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// A rigid transformation. The real part holds the rotation and the dual part holds the
+// translation t as 0.5 * t * real.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DualQuaternion<T: BaseFloat> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+
+impl<T: BaseFloat> DualQuaternion<T> {
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        DualQuaternion { real, dual }
+    }
+
+    pub fn identity() -> Self {
+        DualQuaternion::new(Quaternion::identity(), Quaternion::zero())
+    }
+
+    // Rotates first and translates afterwards, like Matrix4::new_translation(t) * Matrix4::from(rotation).
+    pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vector3<T>) -> Self {
+        let two = T::one() + T::one();
+        let t = Quaternion::from_vector_scalar(translation, T::zero());
+        DualQuaternion::new(rotation, t * rotation / two)
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        DualQuaternion::new(rotation, Quaternion::zero())
+    }
+
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        DualQuaternion::from_rotation_translation(Quaternion::identity(), translation)
+    }
+
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    #[inline]
+    pub fn translation(&self) -> Vector3<T> {
+        let two = T::one() + T::one();
+        (self.dual * self.real.conjugate()).vector() * two
+    }
+
+    // Conjugates both parts. For unit dual quaternions this is the inverse.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let real = self.real.inverse()?;
+        Some(DualQuaternion::new(real, -(real * self.dual * real)))
+    }
+
+    // Scales the dual quaternion to unit length and removes the component of the dual part that
+    // is parallel to the real part. Needed after blending several dual quaternions.
+    pub fn normalize(&self) -> Self {
+        let n = self.real.length();
+        let real = self.real / n;
+        let dual = self.dual / n;
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    #[inline]
+    pub fn transform_point(&self, p: Vector3<T>) -> Vector3<T> {
+        self.real * p + self.translation()
+    }
+
+    #[inline]
+    pub fn transform_vector(&self, v: Vector3<T>) -> Vector3<T> {
+        self.real * v
+    }
+
+    // Returns (axis, angle, pitch, moment) of the screw motion described by the unit dual
+    // quaternion. For a pure translation the angle is zero and the axis is the direction of the
+    // translation.
+    fn screw_parameters(&self) -> (Vector3<T>, T, T, Vector3<T>) {
+        let two = T::one() + T::one();
+        let t = self.translation();
+        let sin_h = self.real.vector().length();
+        if sin_h <= T::epsilon() {
+            let d = t.length();
+            let axis = if d == T::zero() { Vector3::new(T::zero(), T::zero(), T::one()) } else { t / d };
+            (axis, T::zero(), d, Vector3::zero())
+        } else {
+            let axis = self.real.vector() / sin_h;
+            let angle = two * T::atan2(sin_h, self.real.w);
+            let d = t.dot(axis);
+            let cot_h = self.real.w / sin_h;
+            let moment = (t.cross(axis) + (t - axis * d) * cot_h) / two;
+            (axis, angle, d, moment)
+        }
+    }
+
+    fn from_screw(axis: Vector3<T>, angle: T, d: T, moment: Vector3<T>) -> Self {
+        let two = T::one() + T::one();
+        let (sin_h, cos_h) = T::sin_cos(angle / two);
+        let real = Quaternion::from_vector_scalar(axis * sin_h, cos_h);
+        let dual = Quaternion::from_vector_scalar(moment * sin_h + axis * (d / two * cos_h), -d / two * sin_h);
+        DualQuaternion::new(real, dual)
+    }
+
+    // Raises the unit dual quaternion to the power of `t` by scaling its screw parameters.
+    pub fn pow(&self, t: T) -> Self {
+        let (axis, angle, d, moment) = self.screw_parameters();
+        if angle == T::zero() {
+            DualQuaternion::from_translation(axis * (d * t))
+        } else {
+            DualQuaternion::from_screw(axis, angle * t, d * t, moment)
+        }
+    }
+
+    // Screw linear interpolation along the shortest path.
+    pub fn sclerp(&self, other: Self, t: T) -> Self {
+        let other = if self.real.dot(other.real) < T::zero() { -other } else { other };
+        *self * (self.conjugate() * other).pow(t)
+    }
+}
+
+// dq + dq
+implement_binary_operator!(Add<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn add(lhs, rhs) -> DualQuaternion<T> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+);
+
+// dq += dq
+implement_binary_assign_operator!(AddAssign<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn add_assign(lhs, rhs) {{
+        lhs.real += rhs.real;
+        lhs.dual += rhs.dual;
+    }}
+);
+
+// dq - dq
+implement_binary_operator!(Sub<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn sub(lhs, rhs) -> DualQuaternion<T> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+);
+
+// dq -= dq
+implement_binary_assign_operator!(SubAssign<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn sub_assign(lhs, rhs) {{
+        lhs.real -= rhs.real;
+        lhs.dual -= rhs.dual;
+    }}
+);
+
+// dq * dq (applies rhs first)
+implement_binary_operator!(Mul<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn mul(lhs, rhs) -> DualQuaternion<T> {
+        DualQuaternion::new(lhs.real * rhs.real, lhs.real * rhs.dual + lhs.dual * rhs.real)
+    }
+);
+
+// dq *= dq
+implement_binary_assign_operator!(MulAssign<DualQuaternion<T>> for DualQuaternion<T> where T: BaseFloat,
+    fn mul_assign(lhs, rhs) {{
+        *lhs = *lhs * rhs;
+    }}
+);
+
+// dq * s
+implement_binary_operator!(Mul<T> for DualQuaternion<T> where T: BaseFloat,
+    fn mul(dq, s) -> DualQuaternion<T> {
+        DualQuaternion::new(dq.real * s, dq.dual * s)
+    }
+);
+
+// dq *= s
+implement_binary_assign_operator!(MulAssign<T> for DualQuaternion<T> where T: BaseFloat,
+    fn mul_assign(dq, s) {{
+        dq.real *= s;
+        dq.dual *= s;
+    }}
+);
+
+implement_unary_operator!(Neg for DualQuaternion<T> where T: BaseFloat,
+    fn neg(dq) -> DualQuaternion<T> {
+        DualQuaternion::new(-dq.real, -dq.dual)
+    }
+);
+
+impl<T: BaseFloat> Default for DualQuaternion<T> {
+    fn default() -> Self {
+        DualQuaternion::identity()
+    }
+}
+
+// dq and -dq describe the same transformation and are therefore considered equal.
+impl<T: BaseFloat> ApproxEq for DualQuaternion<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        let q_eq = |a: &Quaternion<T>, b: &Quaternion<T>| {
+            a.x.approx_eq(&b.x, epsilon, ulps) &&
+            a.y.approx_eq(&b.y, epsilon, ulps) &&
+            a.z.approx_eq(&b.z, epsilon, ulps) &&
+            a.w.approx_eq(&b.w, epsilon, ulps)
+        };
+        (q_eq(&self.real, &other.real) && q_eq(&self.dual, &other.dual)) ||
+        (q_eq(&self.real, &-other.real) && q_eq(&self.dual, &-other.dual))
+    }
+}
+
+// --------------------------------------------------------------------------
+// Conversions
+// --------------------------------------------------------------------------
+
+impl<T: BaseFloat> From<DualQuaternion<T>> for Matrix4<T> {
+    fn from(dq: DualQuaternion<T>) -> Self {
+        let mut m = Matrix4::from(dq.real);
+        let t = dq.translation();
+        m.m03 = t.x;
+        m.m13 = t.y;
+        m.m23 = t.z;
+        m
+    }
+}
+
+// The matrix is expected to be a rigid transformation (rotation and translation only).
+impl<T: BaseFloat> From<Matrix4<T>> for DualQuaternion<T> {
+    fn from(m: Matrix4<T>) -> Self {
+        DualQuaternion::from_rotation_translation(Quaternion::from(m), Vector3::new(m.m03, m.m13, m.m23))
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use crate::dual_quaternion::*;
+    use crate::quaternion::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+    use crate::traits::*;
+
+    use std::f32::consts::FRAC_PI_2;
+
+    type DualQuat = DualQuaternion<f32>;
+    type Quat = Quaternion<f32>;
+
+    type Mat4 = Matrix4<f32>;
+
+    type Vec3 = Vector3<f32>;
+    type Vec4 = Vector4<f32>;
+
+    const EPS: f32 = 8.0*f32::EPSILON;
+
+    fn mat4_transform_point(m: &Mat4, p: Vec3) -> Vec3 {
+        (m * Vec4::new(p.x, p.y, p.z, 1.0)).wdiv()
+    }
+
+    // --------------------------------------------------------------------------
+    // Contructors
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn dual_quaternion_identity() {
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(DualQuat::identity().transform_point(p), p);
+        assert_eq!(DualQuat::default(), DualQuat::identity());
+    }
+
+    #[test]
+    fn dual_quaternion_rotation_translation() {
+        let r = Quat::from_rotation_z(0.8);
+        let t = Vec3::new(1.0, -2.0, 0.5);
+        let dq = DualQuat::from_rotation_translation(r, t);
+        assert!(dq.rotation().approx_eq(&r, EPS, 2));
+        assert!(dq.translation().approx_eq(&t, EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Transformation
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn dual_quaternion_transform_point() {
+        let dq = DualQuat::from_rotation_translation(Quat::from_rotation_z(FRAC_PI_2), Vec3::new(1.0, 0.0, 0.0));
+        assert!(dq.transform_point(Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(1.0, 1.0, 0.0), EPS, 2));
+        assert!(dq.transform_vector(Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(0.0, 1.0, 0.0), EPS, 2));
+    }
+
+    #[test]
+    fn dual_quaternion_composition_matches_matrix() {
+        let a = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation_x(0.3);
+        let b = Mat4::new_translation(-2.0, 0.5, 1.0) * Mat4::new_rotation_y(1.2);
+        let dq = DualQuat::from(a) * DualQuat::from(b);
+        let p = Vec3::new(0.3, -0.7, 2.0);
+        assert!(dq.transform_point(p).approx_eq(&mat4_transform_point(&(a * b), p), EPS, 4));
+        assert!(Mat4::from(dq).approx_eq(&(a * b), EPS, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_inverse() {
+        let dq = DualQuat::from_rotation_translation(Quat::from_euler(0.2, 0.4, -0.3), Vec3::new(3.0, 1.0, -1.0));
+        assert!((dq * dq.inverse().unwrap()).approx_eq(&DualQuat::identity(), EPS, 4));
+        assert!(dq.inverse().unwrap().approx_eq(&dq.conjugate(), EPS, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_normalize_blend() {
+        let a = DualQuat::from_rotation_translation(Quat::from_rotation_x(0.2), Vec3::new(1.0, 0.0, 0.0));
+        let b = DualQuat::from_rotation_translation(Quat::from_rotation_x(0.6), Vec3::new(1.0, 0.0, 0.0));
+        let blend = (a * 0.5 + b * 0.5).normalize();
+        assert!(blend.real.length().approx_eq(&1.0, EPS, 2));
+        assert!(blend.real.dot(blend.dual).abs() < EPS);
+        assert!(blend.translation().approx_eq(&Vec3::new(1.0, 0.0, 0.0), EPS, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // Conversions
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn dual_quaternion_matrix_round_trip() {
+        let m = Mat4::new_translation(4.0, -1.0, 2.0) * Mat4::new_rotation(Vec3::new(0.0, 0.6, 0.8), 2.0);
+        assert!(Mat4::from(DualQuat::from(m)).approx_eq(&m, EPS, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // ScLERP
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn dual_quaternion_sclerp_endpoints() {
+        let a = DualQuat::from_rotation_translation(Quat::from_rotation_y(0.3), Vec3::new(1.0, 2.0, 3.0));
+        let b = DualQuat::from_rotation_translation(Quat::from_rotation_x(1.3), Vec3::new(-1.0, 0.0, 2.0));
+        assert!(a.sclerp(b, 0.0).approx_eq(&a, EPS, 4));
+        assert!(a.sclerp(b, 1.0).approx_eq(&b, 1e-5, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_sclerp_screw() {
+        // A screw motion along and around the z axis.
+        let a = DualQuat::identity();
+        let b = DualQuat::from_rotation_translation(Quat::from_rotation_z(FRAC_PI_2), Vec3::new(0.0, 0.0, 2.0));
+        let half = DualQuat::from_rotation_translation(Quat::from_rotation_z(FRAC_PI_2 / 2.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(a.sclerp(b, 0.5).approx_eq(&half, EPS, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_sclerp_rotation_about_offset_axis() {
+        // Rotating about an axis through (1, 0, 0) keeps that point fixed for every t.
+        let pivot = Mat4::new_translation(1.0, 0.0, 0.0);
+        let m = pivot * Mat4::new_rotation_z(1.5) * pivot.inverse().unwrap();
+        let dq = DualQuat::identity().sclerp(DualQuat::from(m), 0.4);
+        assert!(dq.transform_point(Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(1.0, 0.0, 0.0), 1e-6, 4));
+        assert!(dq.rotation().approx_eq(&Quat::from_rotation_z(0.6), EPS, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_sclerp_pure_translation() {
+        let a = DualQuat::from_translation(Vec3::new(0.0, 0.0, 0.0));
+        let b = DualQuat::from_translation(Vec3::new(2.0, 4.0, -6.0));
+        assert!(a.sclerp(b, 0.25).translation().approx_eq(&Vec3::new(0.5, 1.0, -1.5), EPS, 4));
+    }
+
+    #[test]
+    fn dual_quaternion_sclerp_shortest_path() {
+        let a = DualQuat::from_rotation(Quat::from_rotation_x(0.1));
+        let b = -DualQuat::from_rotation(Quat::from_rotation_x(0.5));
+        assert!(a.sclerp(b, 0.5).rotation().approx_eq(&Quat::from_rotation_x(0.3), EPS, 4));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    quaternion::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EulerOrder {
+    // Tait-Bryan angles
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    // Proper Euler angles
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+// Intrinsic rotations are applied about the axes of the rotating frame, extrinsic rotations
+// about the axes of the fixed frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+impl EulerOrder {
+    // The indices of the three rotation axes.
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0),
+            EulerOrder::XYX => (0, 1, 0),
+            EulerOrder::XZX => (0, 2, 0),
+            EulerOrder::YXY => (1, 0, 1),
+            EulerOrder::YZY => (1, 2, 1),
+            EulerOrder::ZXZ => (2, 0, 2),
+            EulerOrder::ZYZ => (2, 1, 2),
+        }
+    }
+
+    pub fn reversed(self) -> Self {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+            proper => proper,
+        }
+    }
+}
+
+// Rotation by the angles a, b and c about the three axes given by `order`. With an intrinsic
+// frame Euler::new(a, b, c, EulerOrder::XYZ, EulerFrame::Intrinsic) is the same rotation as
+// Matrix4::new_rotation_x(a) * Matrix4::new_rotation_y(b) * Matrix4::new_rotation_z(c).
+// An extrinsic rotation equals the intrinsic rotation with reversed order and angles.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Euler<T: BaseFloat> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub order: EulerOrder,
+    pub frame: EulerFrame,
+}
+
+impl<T: BaseFloat> Euler<T> {
+    pub fn new(a: T, b: T, c: T, order: EulerOrder, frame: EulerFrame) -> Self {
+        Euler { a, b, c, order, frame }
+    }
+
+    pub fn intrinsic(a: T, b: T, c: T, order: EulerOrder) -> Self {
+        Euler::new(a, b, c, order, EulerFrame::Intrinsic)
+    }
+
+    pub fn extrinsic(a: T, b: T, c: T, order: EulerOrder) -> Self {
+        Euler::new(a, b, c, order, EulerFrame::Extrinsic)
+    }
+
+    // Returns the same rotation expressed as intrinsic angles.
+    pub fn to_intrinsic(self) -> Self {
+        match self.frame {
+            EulerFrame::Intrinsic => self,
+            EulerFrame::Extrinsic => Euler::intrinsic(self.c, self.b, self.a, self.order.reversed()),
+        }
+    }
+
+    // Returns the same rotation expressed in another order and frame.
+    pub fn to_order(self, order: EulerOrder, frame: EulerFrame) -> Self {
+        Euler::from_matrix3(&Matrix3::from(self), order, frame)
+    }
+
+    // Extracts the angles from a pure rotation matrix. For Tait-Bryan orders the middle angle lies
+    // in [-pi/2, pi/2], for proper Euler orders in [0, pi]. In gimbal lock only the sum or
+    // difference of the first and last angle is defined and the last angle is set to zero.
+    pub fn from_matrix3(m: &Matrix3<T>, order: EulerOrder, frame: EulerFrame) -> Self {
+        let intrinsic_order = match frame {
+            EulerFrame::Intrinsic => order,
+            EulerFrame::Extrinsic => order.reversed(),
+        };
+        let (i, j, k0) = intrinsic_order.axes();
+        let proper = i == k0;
+        let k = 3 - i - j;
+        let s = if (j + 3 - i) % 3 == 1 { T::one() } else { -T::one() };
+        let r = |row: usize, col: usize| m.row_elem(row, col);
+        let threshold = T::from(16.0).unwrap() * T::epsilon();
+
+        let (a, b, c) = if proper {
+            let sy = T::sqrt(r(i, j) * r(i, j) + r(i, k) * r(i, k));
+            let b = T::atan2(sy, r(i, i));
+            if sy > threshold {
+                (T::atan2(r(j, i), -s * r(k, i)), b, T::atan2(r(i, j), s * r(i, k)))
+            } else {
+                (T::atan2(s * r(k, j), r(j, j)), b, T::zero())
+            }
+        } else {
+            let cy = T::sqrt(r(i, i) * r(i, i) + r(i, j) * r(i, j));
+            let b = T::atan2(s * r(i, k), cy);
+            if cy > threshold {
+                (T::atan2(-s * r(j, k), r(k, k)), b, T::atan2(-s * r(i, j), r(i, i)))
+            } else {
+                (T::atan2(s * r(k, j), r(j, j)), b, T::zero())
+            }
+        };
+
+        match frame {
+            EulerFrame::Intrinsic => Euler::new(a, b, c, order, frame),
+            EulerFrame::Extrinsic => Euler::new(c, b, a, order, frame),
+        }
+    }
+
+    // Only the upper-left 3x3 part is used and it is expected to be a pure rotation.
+    pub fn from_matrix4(m: &Matrix4<T>, order: EulerOrder, frame: EulerFrame) -> Self {
+        Euler::from_matrix3(&Matrix3::from(*m), order, frame)
+    }
+
+    pub fn from_quaternion(q: &Quaternion<T>, order: EulerOrder, frame: EulerFrame) -> Self {
+        Euler::from_matrix3(&Matrix3::from(*q), order, frame)
+    }
+}
+
+fn axis_rotation<T: BaseFloat>(axis: usize, angle: T) -> Quaternion<T> {
+    let mut v = Vector3::zero();
+    *(&mut v).elem(axis) = T::one();
+    Quaternion::from_axis_angle(v, angle)
+}
+
+impl<T: BaseFloat> From<Euler<T>> for Quaternion<T> {
+    fn from(e: Euler<T>) -> Self {
+        let e = e.to_intrinsic();
+        let (i, j, k) = e.order.axes();
+        axis_rotation(i, e.a) * axis_rotation(j, e.b) * axis_rotation(k, e.c)
+    }
+}
+
+impl<T: BaseFloat> From<Euler<T>> for Matrix3<T> {
+    fn from(e: Euler<T>) -> Self {
+        Matrix3::from(Quaternion::from(e))
+    }
+}
+
+impl<T: BaseFloat> From<Euler<T>> for Matrix4<T> {
+    fn from(e: Euler<T>) -> Self {
+        Matrix4::from(Quaternion::from(e))
+    }
+}
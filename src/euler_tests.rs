@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use crate::euler::*;
+    use crate::quaternion::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+
+    use std::f32::consts::{PI,FRAC_PI_2};
+
+    type Eul = Euler<f32>;
+    type Quat = Quaternion<f32>;
+
+    type Mat3 = Matrix3<f32>;
+    type Mat4 = Matrix4<f32>;
+
+    const EPS: f32 = 1e-5;
+
+    const TAIT_BRYAN: [EulerOrder; 6] = [
+        EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+    ];
+
+    const PROPER: [EulerOrder; 6] = [
+        EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY, EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ,
+    ];
+
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    fn assert_angles_eq(e: &Eul, a: f32, b: f32, c: f32) {
+        assert!(e.a.approx_eq(&a, EPS, 4) && e.b.approx_eq(&b, EPS, 4) && e.c.approx_eq(&c, EPS, 4),
+            "{:?} != ({}, {}, {})", e, a, b, c);
+    }
+
+    // --------------------------------------------------------------------------
+    // To matrix
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn euler_intrinsic_xyz_matches_matrix_chain() {
+        let (x, y, z) = (0.3, -0.8, 1.9);
+        let m = Mat4::new_rotation_x(x) * Mat4::new_rotation_y(y) * Mat4::new_rotation_z(z);
+        assert!(Mat4::from(Eul::intrinsic(x, y, z, EulerOrder::XYZ)).approx_eq(&m, EPS, 4));
+        assert!(Quat::from(Eul::intrinsic(x, y, z, EulerOrder::XYZ)).approx_eq(&Quat::from_euler(x, y, z), EPS, 4));
+    }
+
+    #[test]
+    fn euler_extrinsic_xyz_matches_matrix_chain() {
+        let (x, y, z) = (0.3, -0.8, 1.9);
+        let m = Mat4::new_rotation_z(z) * Mat4::new_rotation_y(y) * Mat4::new_rotation_x(x);
+        assert!(Mat4::from(Eul::extrinsic(x, y, z, EulerOrder::XYZ)).approx_eq(&m, EPS, 4));
+    }
+
+    #[test]
+    fn euler_proper_zxz_matches_matrix_chain() {
+        let (a, b, c) = (0.4, 1.1, -2.0);
+        let m = Mat4::new_rotation_z(a) * Mat4::new_rotation_x(b) * Mat4::new_rotation_z(c);
+        assert!(Mat4::from(Eul::intrinsic(a, b, c, EulerOrder::ZXZ)).approx_eq(&m, EPS, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // From matrix
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn euler_round_trip_tait_bryan() {
+        let (a, b, c) = (0.7, -1.1, 2.5);
+        for &order in TAIT_BRYAN.iter() {
+            for &frame in FRAMES.iter() {
+                let m = Mat3::from(Eul::new(a, b, c, order, frame));
+                let e = Eul::from_matrix3(&m, order, frame);
+                assert_eq!((e.order, e.frame), (order, frame));
+                assert_angles_eq(&e, a, b, c);
+            }
+        }
+    }
+
+    #[test]
+    fn euler_round_trip_proper() {
+        let (a, b, c) = (-0.7, 2.1, 1.5);
+        for &order in PROPER.iter() {
+            for &frame in FRAMES.iter() {
+                let m = Mat3::from(Eul::new(a, b, c, order, frame));
+                let e = Eul::from_matrix3(&m, order, frame);
+                assert_angles_eq(&e, a, b, c);
+            }
+        }
+    }
+
+    #[test]
+    fn euler_round_trip_quaternion_and_matrix4() {
+        let e = Eul::intrinsic(0.2, 0.3, -0.4, EulerOrder::YXZ);
+        assert_angles_eq(&Eul::from_quaternion(&Quat::from(e), e.order, e.frame), 0.2, 0.3, -0.4);
+        assert_angles_eq(&Eul::from_matrix4(&Mat4::from(e), e.order, e.frame), 0.2, 0.3, -0.4);
+    }
+
+    #[test]
+    fn euler_gimbal_lock_tait_bryan() {
+        for &order in TAIT_BRYAN.iter() {
+            for &frame in FRAMES.iter() {
+                for &b in &[FRAC_PI_2, -FRAC_PI_2] {
+                    let m = Mat3::from(Eul::new(0.5, b, 0.3, order, frame));
+                    let e = Eul::from_matrix3(&m, order, frame);
+                    assert!(e.b.approx_eq(&b, 1e-3, 4), "{:?}", e);
+                    assert!(e.a.is_finite() && e.c.is_finite());
+                    assert!(Mat3::from(e).approx_eq(&m, EPS, 4), "{:?}", order);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn euler_gimbal_lock_proper() {
+        for &order in PROPER.iter() {
+            for &frame in FRAMES.iter() {
+                for &b in &[0.0, PI] {
+                    let m = Mat3::from(Eul::new(0.5, b, 0.3, order, frame));
+                    let e = Eul::from_matrix3(&m, order, frame);
+                    assert!(e.b.approx_eq(&b, 1e-3, 4), "{:?}", e);
+                    assert!(Mat3::from(e).approx_eq(&m, EPS, 4), "{:?}", order);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn euler_gimbal_lock_sets_last_angle_to_zero() {
+        let m = Mat3::from(Eul::intrinsic(0.5, FRAC_PI_2, 0.3, EulerOrder::XYZ));
+        let e = Eul::from_matrix3(&m, EulerOrder::XYZ, EulerFrame::Intrinsic);
+        assert_angles_eq(&e, 0.8, FRAC_PI_2, 0.0);
+    }
+
+    #[test]
+    fn euler_to_order() {
+        let e = Eul::intrinsic(0.2, 0.5, 0.9, EulerOrder::XYZ);
+        let r = e.to_order(EulerOrder::ZXZ, EulerFrame::Extrinsic);
+        assert!(Mat3::from(r).approx_eq(&Mat3::from(e), EPS, 4));
+        assert_angles_eq(&e.to_order(EulerOrder::ZYX, EulerFrame::Extrinsic), 0.9, 0.5, 0.2);
+    }
+
+    #[test]
+    fn euler_axis_rotation() {
+        let e = Eul::intrinsic(0.0, 0.0, FRAC_PI_2, EulerOrder::XYZ);
+        let v = Quat::from(e) * Vector3::new(1.0, 0.0, 0.0);
+        assert!(v.approx_eq(&Vector3::new(0.0, 1.0, 0.0), EPS, 4));
+    }
+}
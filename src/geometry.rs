@@ -0,0 +1,568 @@
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    clip_space::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// A half-line starting at origin. The direction does not have to be normalized; all hit
+// distances are measured in multiples of its length.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ray3<T: BaseFloat> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit<T: BaseFloat> {
+    pub distance: T,
+    pub normal: Vector3<T>,
+}
+
+// The barycentric coordinates (u, v) are the weights of the second and third vertex. The weight
+// of the first one is 1 - u - v.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TriangleHit<T: BaseFloat> {
+    pub distance: T,
+    pub normal: Vector3<T>,
+    pub barycentric: Vector2<T>,
+}
+
+impl<T: BaseFloat> Ray3<T> {
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Ray3 { origin, direction }
+    }
+
+    pub fn from_points(from: Vector3<T>, to: Vector3<T>) -> Self {
+        Ray3::new(from, (to - from).normalize())
+    }
+
+    #[inline]
+    pub fn point_at(&self, distance: T) -> Vector3<T> {
+        self.origin + self.direction * distance
+    }
+
+    // The normal points away from the center. If the origin lies inside the sphere the exit point
+    // is returned. Spheres without a positive radius have no normal and are never hit.
+    pub fn intersect_sphere(&self, center: Vector3<T>, radius: T) -> Option<RayHit<T>> {
+        if radius <= T::zero() {
+            return None;
+        }
+        let oc = self.origin - center;
+        let a = self.direction.dot(self.direction);
+        let half_b = oc.dot(self.direction);
+        let c = oc.dot(oc) - radius * radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::zero() || a == T::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let mut distance = (-half_b - root) / a;
+        if distance < T::zero() {
+            distance = (-half_b + root) / a;
+            if distance < T::zero() {
+                return None;
+            }
+        }
+
+        let normal = (self.point_at(distance) - center) / radius;
+        Some(RayHit { distance, normal })
+    }
+
+    // The plane passes through point and is oriented by normal, which is also returned as the
+    // normal of the hit. Rays parallel to the plane never hit it.
+    pub fn intersect_plane(&self, point: Vector3<T>, normal: Vector3<T>) -> Option<RayHit<T>> {
+        let denom = self.direction.dot(normal);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let distance = (point - self.origin).dot(normal) / denom;
+        if distance < T::zero() {
+            return None;
+        }
+
+        Some(RayHit { distance, normal })
+    }
+
+    // Möller–Trumbore. Both sides of the triangle are hit; the normal is the one of the
+    // counterclockwise winding a, b, c regardless of the side.
+    pub fn intersect_triangle(&self, a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<TriangleHit<T>> {
+        let e1 = b - a;
+        let e2 = c - a;
+        let p = self.direction.cross(e2);
+        let det = e1.dot(p);
+        // det is the sine of the angle between the ray and the plane of the triangle scaled by
+        // the lengths below, so the threshold has to be scaled the same way
+        if det.abs() <= T::epsilon() * e1.length() * e2.length() * self.direction.length() {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+
+        let q = s.cross(e1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+
+        let distance = e2.dot(q) * inv_det;
+        if distance < T::zero() {
+            return None;
+        }
+
+        Some(TriangleHit {
+            distance,
+            normal: e1.cross(e2).normalize(),
+            barycentric: Vector2::new(u, v),
+        })
+    }
+
+    // Slab test against an axis-aligned box. The normal is the one of the face through which the
+    // ray enters the box. If the origin lies inside the box the exit face is returned instead.
+    pub fn intersect_aabb(&self, min: Vector3<T>, max: Vector3<T>) -> Option<RayHit<T>> {
+        let mut t_near = T::neg_infinity();
+        let mut t_far = T::infinity();
+        let mut near_axis = 0;
+        let mut far_axis = 0;
+
+        for axis in 0..3 {
+            let o = self.origin.elem(axis);
+            let d = self.direction.elem(axis);
+            if d == T::zero() {
+                if o < min.elem(axis) || o > max.elem(axis) {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv_d = T::one() / d;
+            let t0 = (min.elem(axis) - o) * inv_d;
+            let t1 = (max.elem(axis) - o) * inv_d;
+            let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+            if t0 > t_near {
+                t_near = t0;
+                near_axis = axis;
+            }
+            if t1 < t_far {
+                t_far = t1;
+                far_axis = axis;
+            }
+            if t_near > t_far {
+                return None;
+            }
+        }
+
+        if t_far < T::zero() {
+            return None;
+        }
+
+        let (distance, axis, sign) = if t_near >= T::zero() {
+            (t_near, near_axis, -T::one())
+        } else {
+            (t_far, far_axis, T::one())
+        };
+
+        // Entering faces oppose the direction, exit faces follow it.
+        let mut normal = Vector3::zero();
+        *(&mut normal).elem(axis) = if self.direction.elem(axis) < T::zero() { -sign } else { sign };
+        Some(RayHit { distance, normal })
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Ray3<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.origin.approx_eq(&other.origin, epsilon, ulps) &&
+        self.direction.approx_eq(&other.direction, epsilon, ulps)
+    }
+}
+
+// Axis-aligned boxes are closed: points on the boundary are contained and boxes that only touch
+// still intersect. A box is valid as long as min <= max holds for every component.
+macro_rules! implement_aabb {
+    ($type:ident {
+        vector_type: $vector_type:ident,
+        elems: { $($member:ident),+ }
+    }) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+        pub struct $type<T: Base> {
+            pub min: $vector_type<T>,
+            pub max: $vector_type<T>,
+        }
+
+        impl<T: Base> $type<T> {
+            pub fn new(min: $vector_type<T>, max: $vector_type<T>) -> Self {
+                $type { min, max }
+            }
+
+            // The smallest box containing all points, or None if there are no points.
+            pub fn from_points(points: &[$vector_type<T>]) -> Option<Self> {
+                let (first, rest) = points.split_first()?;
+                Some(rest.iter().fold($type::new(*first, *first), |aabb, &p| aabb.extend(p)))
+            }
+
+            pub fn extend(&self, point: $vector_type<T>) -> Self {
+                $type::new(self.min.cw_min(point), self.max.cw_max(point))
+            }
+
+            pub fn union(&self, other: &Self) -> Self {
+                $type::new(self.min.cw_min(other.min), self.max.cw_max(other.max))
+            }
+
+            // The overlap of both boxes, or None if they are disjoint.
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let res = $type::new(self.min.cw_max(other.min), self.max.cw_min(other.max));
+                if $(res.min.$member > res.max.$member)||+ {
+                    return None;
+                }
+                Some(res)
+            }
+
+            pub fn intersects(&self, other: &Self) -> bool {
+                self.intersection(other).is_some()
+            }
+
+            pub fn contains(&self, point: $vector_type<T>) -> bool {
+                $(point.$member >= self.min.$member && point.$member <= self.max.$member)&&+
+            }
+
+            pub fn contains_aabb(&self, other: &Self) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            // For integer boxes the center is rounded towards zero.
+            pub fn center(&self) -> $vector_type<T> {
+                (self.min + self.max) / (T::one() + T::one())
+            }
+
+            // The full size of the box along every axis.
+            pub fn extents(&self) -> $vector_type<T> {
+                self.max - self.min
+            }
+
+            // The point of the box nearest to the given one. Points inside are returned unchanged.
+            pub fn closest_point(&self, point: $vector_type<T>) -> $vector_type<T> {
+                point.cw_max(self.min).cw_min(self.max)
+            }
+        }
+
+        impl<T: BaseFloat> ApproxEq for $type<T>
+            where T: ApproxEq<Flt=T>
+        {
+            type Flt = T;
+            #[inline]
+            fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+                self.min.approx_eq(&other.min, epsilon, ulps) &&
+                self.max.approx_eq(&other.max, epsilon, ulps)
+            }
+        }
+    }
+}
+
+implement_aabb!(Aabb2 { vector_type: Vector2, elems: { x, y } });
+implement_aabb!(Aabb3 { vector_type: Vector3, elems: { x, y, z } });
+
+impl<T: Base> Aabb2<T> {
+    pub fn area(&self) -> T {
+        let e = self.extents();
+        e.x * e.y
+    }
+}
+
+impl<T: Base> Aabb3<T> {
+    pub fn surface_area(&self) -> T {
+        let e = self.extents();
+        (e.x * e.y + e.y * e.z + e.z * e.x) * (T::one() + T::one())
+    }
+
+    pub fn volume(&self) -> T {
+        let e = self.extents();
+        e.x * e.y * e.z
+    }
+
+    // The tight box around the transformed corners of this one. The matrix has to be affine; the
+    // bounds are accumulated per output axis as in Arvo's "Transforming Axis-Aligned Bounding Boxes".
+    pub fn transform(&self, m: &Matrix4<T>) -> Self {
+        let mut min = Vector3::zero();
+        let mut max = Vector3::zero();
+        for i in 0..3 {
+            let row = m.row(i);
+            let (mut lo, mut hi) = (row.w, row.w);
+            for j in 0..3 {
+                let a = row.elem(j) * self.min.elem(j);
+                let b = row.elem(j) * self.max.elem(j);
+                if a < b {
+                    lo += a;
+                    hi += b;
+                } else {
+                    lo += b;
+                    hi += a;
+                }
+            }
+            *(&mut min).elem(i) = lo;
+            *(&mut max).elem(i) = hi;
+        }
+        Aabb3::new(min, max)
+    }
+}
+
+// A plane with unit normal, containing every point p with dot(normal, p) + distance = 0. Points
+// on the side the normal points to have a positive signed distance.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plane<T: BaseFloat> {
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+impl<T: BaseFloat> Plane<T> {
+    pub fn new(normal: Vector3<T>, distance: T) -> Self {
+        Plane { normal, distance }
+    }
+
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let normal = normal.normalize();
+        Plane::new(normal, -normal.dot(point))
+    }
+
+    // The normal faces the side from which a, b, c appear counterclockwise. Returns None if the
+    // points are collinear.
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        if normal.length() == T::zero() {
+            return None;
+        }
+        Some(Plane::from_point_normal(a, normal))
+    }
+
+    // Interprets (x, y, z, w) as the coefficients of x*px + y*py + z*pz + w = 0 and rescales them
+    // to a unit normal.
+    pub fn from_vector4(v: Vector4<T>) -> Self {
+        let length = Vector3::new(v.x, v.y, v.z).length();
+        Plane::new(Vector3::new(v.x, v.y, v.z) / length, v.w / length)
+    }
+
+    pub fn to_vector4(&self) -> Vector4<T> {
+        Vector4::new(self.normal.x, self.normal.y, self.normal.z, self.distance)
+    }
+
+    #[inline]
+    pub fn signed_distance(&self, point: Vector3<T>) -> T {
+        self.normal.dot(point) + self.distance
+    }
+
+    pub fn project_point(&self, point: Vector3<T>) -> Vector3<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    // Intersects the infinite line through point along direction. Returns None if the line is
+    // parallel to the plane.
+    pub fn intersect_line(&self, point: Vector3<T>, direction: Vector3<T>) -> Option<Vector3<T>> {
+        let denom = self.normal.dot(direction);
+        if denom == T::zero() {
+            return None;
+        }
+        Some(point - direction * (self.signed_distance(point) / denom))
+    }
+
+    // The common point of three planes, or None if two of them are parallel or all three share a
+    // line.
+    pub fn intersect_planes(a: &Self, b: &Self, c: &Self) -> Option<Vector3<T>> {
+        let bc = b.normal.cross(c.normal);
+        let det = a.normal.dot(bc);
+        if det == T::zero() {
+            return None;
+        }
+        let ca = c.normal.cross(a.normal);
+        let ab = a.normal.cross(b.normal);
+        Some(-(bc * a.distance + ca * b.distance + ab * c.distance) / det)
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Plane<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.normal.approx_eq(&other.normal, epsilon, ulps) &&
+        self.distance.approx_eq(&other.distance, epsilon, ulps)
+    }
+}
+
+impl<T: BaseFloat> Matrix4<T> {
+    // Mirrors points at the plane.
+    pub fn new_reflection(plane: &Plane<T>) -> Self {
+        let two = T::one() + T::one();
+        let (n, d) = (plane.normal, plane.distance);
+        Matrix4::from_components_row_major(
+            T::one() - two*n.x*n.x, -two*n.x*n.y          , -two*n.x*n.z          , -two*n.x*d,
+            -two*n.y*n.x          , T::one() - two*n.y*n.y, -two*n.y*n.z          , -two*n.y*d,
+            -two*n.z*n.x          , -two*n.z*n.y          , T::one() - two*n.z*n.z, -two*n.z*d,
+            T::zero()             , T::zero()             , T::zero()             , T::one()
+        )
+    }
+
+    // Projects points onto the plane along the rays from a light. The light is given in
+    // homogeneous coordinates: w = 1 for a point light at (x, y, z), w = 0 for a directional
+    // light shining along (x, y, z) towards the plane. The result is scaled by dot(plane, light),
+    // so transformed points always need the perspective divide.
+    pub fn new_planar_shadow(plane: &Plane<T>, light: Vector4<T>) -> Self {
+        let p = plane.to_vector4();
+        let l = light;
+        let d = p.dot(l);
+        Matrix4::from_components_row_major(
+            d - l.x*p.x, -l.x*p.y   , -l.x*p.z   , -l.x*p.w,
+            -l.y*p.x   , d - l.y*p.y, -l.y*p.z   , -l.y*p.w,
+            -l.z*p.x   , -l.z*p.y   , d - l.z*p.z, -l.z*p.w,
+            -l.w*p.x   , -l.w*p.y   , -l.w*p.z   , d - l.w*p.w
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+// The six clipping planes of a view volume. All normals point into the volume, so a point is
+// inside if its signed distance to every plane is non-negative.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Frustum<T: BaseFloat> {
+    pub left: Plane<T>,
+    pub right: Plane<T>,
+    pub bottom: Plane<T>,
+    pub top: Plane<T>,
+    pub near: Plane<T>,
+    // None for projections with an infinite far plane.
+    pub far: Option<Plane<T>>,
+}
+
+impl<T: BaseFloat> Frustum<T> {
+    // Extracts the planes of the volume that m maps onto the [-1, 1] clip cube (Gribb/Hartmann).
+    // With a view-projection matrix the planes are in world space, with a projection matrix
+    // alone they are in view space.
+    pub fn from_matrix(m: &Matrix4<T>) -> Self {
+        Self::from_matrix_with_clip_space(m, ClipSpace::OPENGL)
+    }
+
+    // Like from_matrix for matrices mapping to the given clip space. The far plane is left out if
+    // its normal vanishes next to the near plane's, as for projections with an infinite far plane
+    // or a far plane beyond the precision of T.
+    pub fn from_matrix_with_clip_space(m: &Matrix4<T>, clip: ClipSpace) -> Self {
+        let (r0, r1, r2, r3) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        let depth_min = match clip.depth_range {
+            DepthRange::NegativeOneToOne => r3 + r2,
+            DepthRange::ZeroToOne => r2,
+        };
+        let depth_max = r3 - r2;
+        let (near, far) = if clip.reverse_z { (depth_max, depth_min) } else { (depth_min, depth_max) };
+        let length = |v: Vector4<T>| Vector3::new(v.x, v.y, v.z).length();
+        let far = if length(far) <= T::from(16.0).unwrap() * T::epsilon() * length(near) {
+            None
+        } else {
+            Some(Plane::from_vector4(far))
+        };
+        Frustum {
+            left: Plane::from_vector4(r3 + r0),
+            right: Plane::from_vector4(r3 - r0),
+            bottom: Plane::from_vector4(r3 + r1),
+            top: Plane::from_vector4(r3 - r1),
+            near: Plane::from_vector4(near),
+            far,
+        }
+    }
+
+    pub fn planes(&self) -> impl Iterator<Item = Plane<T>> {
+        IntoIterator::into_iter([self.left, self.right, self.bottom, self.top, self.near]).chain(self.far)
+    }
+
+    // Points on a plane count as intersecting.
+    pub fn test_point(&self, point: Vector3<T>) -> Containment {
+        self.test_sphere(point, T::zero())
+    }
+
+    pub fn test_sphere(&self, center: Vector3<T>, radius: T) -> Containment {
+        let mut res = Containment::Inside;
+        for plane in self.planes() {
+            let distance = plane.signed_distance(center);
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance <= radius {
+                res = Containment::Intersecting;
+            }
+        }
+        res
+    }
+
+    // Boxes that straddle the corner regions outside the frustum may be reported as intersecting
+    // although they are outside. This is conservative and fine for culling.
+    pub fn test_aabb(&self, aabb: &Aabb3<T>) -> Containment {
+        let mut res = Containment::Inside;
+        for plane in self.planes() {
+            // The corners furthest along and against the plane normal
+            let mut positive = aabb.min;
+            let mut negative = aabb.max;
+            for axis in 0..3 {
+                if plane.normal.elem(axis) >= T::zero() {
+                    *(&mut positive).elem(axis) = aabb.max.elem(axis);
+                    *(&mut negative).elem(axis) = aabb.min.elem(axis);
+                }
+            }
+            if plane.signed_distance(positive) < T::zero() {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(negative) <= T::zero() {
+                res = Containment::Intersecting;
+            }
+        }
+        res
+    }
+
+    // The corners of the frustum. Bit 0 of the index selects right over left, bit 1 top over bottom
+    // and bit 2 far over near. Returns None if the frustum is degenerate or unbounded, e.g. has no
+    // far plane.
+    pub fn corners(&self) -> Option<[Vector3<T>; 8]> {
+        let mut res = [Vector3::zero(); 8];
+        for (i, corner) in res.iter_mut().enumerate() {
+            let x = if i & 1 == 0 { &self.left } else { &self.right };
+            let y = if i & 2 == 0 { &self.bottom } else { &self.top };
+            let z = if i & 4 == 0 { &self.near } else { self.far.as_ref()? };
+            *corner = Plane::intersect_planes(x, y, z)?;
+            if !(corner.x.is_finite() && corner.y.is_finite() && corner.z.is_finite()) {
+                return None;
+            }
+        }
+        Some(res)
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Frustum<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        let far = match (&self.far, &other.far) {
+            (Some(a), Some(b)) => a.approx_eq(b, epsilon, ulps),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        far && self.planes().zip(other.planes()).all(|(a, b)| a.approx_eq(&b, epsilon, ulps))
+    }
+}
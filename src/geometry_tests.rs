@@ -0,0 +1,433 @@
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+    use crate::vector::*;
+    use crate::matrix::*;
+    use crate::point::*;
+    use crate::clip_space::*;
+
+    type Ray = Ray3<f32>;
+
+    type Vec2 = Vector2<f32>;
+    type Vec3 = Vector3<f32>;
+
+    const EPS: f32 = 4.0*f32::EPSILON;
+
+    // --------------------------------------------------------------------------
+    // Ray3
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn ray3_point_at() {
+        let r = Ray::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 0.0, 2.0));
+        assert_eq!(r.point_at(1.5), Vec3::new(1.0, 2.0, 6.0));
+        let r = Ray::from_points(Vec3::zero(), Vec3::new(0.0, 3.0, 4.0));
+        assert!(r.direction.approx_eq(&Vec3::new(0.0, 0.6, 0.8), EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Sphere
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn ray3_intersect_sphere() {
+        let r = Ray::new(Vec3::new(0.0, 0.0, -10.0), Vec3::new(0.0, 0.0, 1.0));
+        let hit = r.intersect_sphere(Vec3::zero(), 2.0).unwrap();
+        assert_eq!(hit.distance, 8.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn ray3_intersect_sphere_scaled_direction() {
+        let r = Ray::new(Vec3::new(0.0, 0.0, -10.0), Vec3::new(0.0, 0.0, 2.0));
+        assert_eq!(r.intersect_sphere(Vec3::zero(), 2.0).unwrap().distance, 4.0);
+    }
+
+    #[test]
+    fn ray3_intersect_sphere_inside() {
+        let r = Ray::new(Vec3::zero(), Vec3::new(1.0, 0.0, 0.0));
+        let hit = r.intersect_sphere(Vec3::zero(), 3.0).unwrap();
+        assert_eq!(hit.distance, 3.0);
+        assert_eq!(hit.normal, Vec3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn ray3_intersect_sphere_miss() {
+        let r = Ray::new(Vec3::new(0.0, 5.0, -10.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(r.intersect_sphere(Vec3::zero(), 2.0).is_none());
+        // Behind the origin
+        let r = Ray::new(Vec3::new(0.0, 0.0, 10.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(r.intersect_sphere(Vec3::zero(), 2.0).is_none());
+    }
+
+    #[test]
+    fn ray3_intersect_sphere_degenerate() {
+        // Passes right through the center
+        let r = Ray::new(Vec3::new(0.0, 0.0, -10.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(r.intersect_sphere(Vec3::zero(), 0.0).is_none());
+        assert!(r.intersect_sphere(Vec3::zero(), -2.0).is_none());
+    }
+
+    // --------------------------------------------------------------------------
+    // Plane
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn ray3_intersect_plane() {
+        let r = Ray::new(Vec3::new(1.0, 5.0, 2.0), Vec3::new(0.0, -1.0, 0.0));
+        let hit = r.intersect_plane(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn ray3_intersect_plane_miss() {
+        let plane = (Vec3::zero(), Vec3::new(0.0, 1.0, 0.0));
+        let parallel = Ray::new(Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        assert!(parallel.intersect_plane(plane.0, plane.1).is_none());
+        let away = Ray::new(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        assert!(away.intersect_plane(plane.0, plane.1).is_none());
+    }
+
+    // --------------------------------------------------------------------------
+    // Triangle
+    // --------------------------------------------------------------------------
+
+    fn triangle() -> (Vec3, Vec3, Vec3) {
+        (Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0))
+    }
+
+    #[test]
+    fn ray3_intersect_triangle() {
+        let (a, b, c) = triangle();
+        let r = Ray::new(Vec3::new(1.0, 2.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        let hit = r.intersect_triangle(a, b, c).unwrap();
+        assert_eq!(hit.distance, 5.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+        assert!(hit.barycentric.approx_eq(&Vec2::new(0.25, 0.5), EPS, 2));
+        let p = a * (1.0 - hit.barycentric.x - hit.barycentric.y) + b * hit.barycentric.x + c * hit.barycentric.y;
+        assert!(p.approx_eq(&r.point_at(hit.distance), EPS, 2));
+    }
+
+    #[test]
+    fn ray3_intersect_triangle_back_side() {
+        let (a, b, c) = triangle();
+        let r = Ray::new(Vec3::new(1.0, 1.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        let hit = r.intersect_triangle(a, b, c).unwrap();
+        assert_eq!(hit.distance, 5.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn ray3_intersect_triangle_miss() {
+        let (a, b, c) = triangle();
+        let outside = Ray::new(Vec3::new(3.0, 3.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        assert!(outside.intersect_triangle(a, b, c).is_none());
+        let parallel = Ray::new(Vec3::new(1.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        assert!(parallel.intersect_triangle(a, b, c).is_none());
+        let behind = Ray::new(Vec3::new(1.0, 1.0, 5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(behind.intersect_triangle(a, b, c).is_none());
+    }
+
+    #[test]
+    fn ray3_intersect_small_triangle() {
+        // Edges of 1e-3 and 1e-4 units
+        for &scale in &[2.5e-4, 2.5e-5] {
+            let (a, b, c) = triangle();
+            let (a, b, c) = (a * scale, b * scale, c * scale);
+            let r = Ray::new(Vec3::new(scale, 2.0 * scale, 5.0), Vec3::new(0.0, 0.0, -1.0));
+            let hit = r.intersect_triangle(a, b, c).unwrap();
+            assert!(hit.distance.approx_eq(&5.0, EPS, 2));
+            assert!(hit.barycentric.approx_eq(&Vec2::new(0.25, 0.5), EPS, 4));
+            let parallel = Ray::new(Vec3::new(scale, scale, 0.0), Vec3::new(1.0, 0.0, 0.0));
+            assert!(parallel.intersect_triangle(a, b, c).is_none());
+        }
+    }
+
+    // --------------------------------------------------------------------------
+    // AABB
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn ray3_intersect_aabb() {
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let r = Ray::new(Vec3::new(-5.0, 0.5, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let hit = r.intersect_aabb(min, max).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+
+        let r = Ray::new(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.0, -2.0, 0.0));
+        let hit = r.intersect_aabb(min, max).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn ray3_intersect_aabb_diagonal() {
+        let (min, max) = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0));
+        let r = Ray::new(Vec3::new(-1.0, -1.0, 1.0), Vec3::new(1.0, 2.0, 0.0));
+        let hit = r.intersect_aabb(min, max).unwrap();
+        assert_eq!(hit.distance, 1.0);
+        assert_eq!(r.point_at(hit.distance), Vec3::new(0.0, 1.0, 1.0));
+        assert_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn ray3_intersect_aabb_inside() {
+        let r = Ray::new(Vec3::zero(), Vec3::new(0.0, 0.0, -1.0));
+        let hit = r.intersect_aabb(Vec3::new(-1.0, -1.0, -2.0), Vec3::new(1.0, 1.0, 1.0)).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn ray3_intersect_aabb_miss() {
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let parallel = Ray::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        assert!(parallel.intersect_aabb(min, max).is_none());
+        let skew = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0));
+        assert!(skew.intersect_aabb(min, max).is_none());
+        let behind = Ray::new(Vec3::new(5.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        assert!(behind.intersect_aabb(min, max).is_none());
+    }
+
+    // --------------------------------------------------------------------------
+    // Aabb2 / Aabb3
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn aabb_from_points() {
+        let points = [Vec3::new(1.0, -2.0, 3.0), Vec3::new(-1.0, 4.0, 0.0), Vec3::new(0.5, 0.0, 5.0)];
+        let aabb = Aabb3::from_points(&points).unwrap();
+        assert_eq!(aabb, Aabb3::new(Vec3::new(-1.0, -2.0, 0.0), Vec3::new(1.0, 4.0, 5.0)));
+        assert!(points.iter().all(|&p| aabb.contains(p)));
+        assert!(Aabb3::<f32>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn aabb_union_intersection() {
+        let a = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 4));
+        let b = Aabb2::new(Vector2::new(2, -1), Vector2::new(6, 3));
+        assert_eq!(a.union(&b), Aabb2::new(Vector2::new(0, -1), Vector2::new(6, 4)));
+        assert_eq!(a.intersection(&b), Some(Aabb2::new(Vector2::new(2, 0), Vector2::new(4, 3))));
+        // Touching boxes intersect in a degenerate box
+        let c = Aabb2::new(Vector2::new(4, 0), Vector2::new(5, 1));
+        assert_eq!(a.intersection(&c), Some(Aabb2::new(Vector2::new(4, 0), Vector2::new(4, 1))));
+        let d = Aabb2::new(Vector2::new(5, 0), Vector2::new(6, 1));
+        assert!(a.intersection(&d).is_none());
+        assert!(!a.intersects(&d));
+    }
+
+    #[test]
+    fn aabb_contains() {
+        let a = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 4));
+        assert!(a.contains(Vector2::new(0, 4)));
+        assert!(a.contains(Vector2::new(2, 3)));
+        assert!(!a.contains(Vector2::new(5, 3)));
+        assert!(a.contains_aabb(&Aabb2::new(Vector2::new(1, 1), Vector2::new(4, 2))));
+        assert!(!a.contains_aabb(&Aabb2::new(Vector2::new(1, 1), Vector2::new(5, 2))));
+    }
+
+    #[test]
+    fn aabb_measures() {
+        let a = Aabb3::new(Vec3::new(-1.0, 0.0, 1.0), Vec3::new(1.0, 3.0, 5.0));
+        assert_eq!(a.center(), Vec3::new(0.0, 1.5, 3.0));
+        assert_eq!(a.extents(), Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(a.surface_area(), 52.0);
+        assert_eq!(a.volume(), 24.0);
+        let tiles = Aabb2::new(Vector2::new(2, 2), Vector2::new(7, 5));
+        assert_eq!(tiles.center(), Vector2::new(4, 3));
+        assert_eq!(tiles.area(), 15);
+    }
+
+    #[test]
+    fn aabb_closest_point() {
+        let a = Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(a.closest_point(Vec3::new(0.5, -0.5, 0.0)), Vec3::new(0.5, -0.5, 0.0));
+        assert_eq!(a.closest_point(Vec3::new(3.0, 0.5, -7.0)), Vec3::new(1.0, 0.5, -1.0));
+    }
+
+    #[test]
+    fn aabb_transform() {
+        let a = Aabb3::new(Vec3::new(-1.0, -2.0, -3.0), Vec3::new(1.0, 2.0, 3.0));
+        let m = Matrix4::new_translation(1.0, 2.0, 3.0) * Matrix4::new_rotation_z(std::f32::consts::FRAC_PI_2);
+        let expected = Aabb3::new(Vec3::new(-1.0, 1.0, 0.0), Vec3::new(3.0, 3.0, 6.0));
+        assert!(a.transform(&m).approx_eq(&expected, EPS, 4));
+        let s = Matrix4::new_scale(-2.0, 1.0, 1.0);
+        assert_eq!(a.transform(&s), Aabb3::new(Vec3::new(-2.0, -2.0, -3.0), Vec3::new(2.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn aabb_transform_matches_corners() {
+        let a = Aabb3::new(Vec3::new(0.0, 1.0, 2.0), Vec3::new(1.0, 3.0, 4.0));
+        let m = Matrix4::new_translation(-1.0, 0.5, 2.0) * Matrix4::new_rotation(Vec3::new(1.0, 1.0, 0.0).normalize(), 0.7);
+        let mut corners = [Vec3::zero(); 8];
+        for (i, c) in corners.iter_mut().enumerate() {
+            let p = Vec3::new(
+                if i & 1 == 0 { a.min.x } else { a.max.x },
+                if i & 2 == 0 { a.min.y } else { a.max.y },
+                if i & 4 == 0 { a.min.z } else { a.max.z },
+            );
+            *c = m.transform_point(Point3::from_vector(p)).to_vector();
+        }
+        assert!(a.transform(&m).approx_eq(&Aabb3::from_points(&corners).unwrap(), EPS, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // Plane
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn plane_construction() {
+        let p = Plane::from_point_normal(Vec3::new(0.0, 3.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(p, Plane::new(Vec3::new(0.0, 1.0, 0.0), -3.0));
+        let q = Plane::from_points(Vec3::new(0.0, 3.0, 0.0), Vec3::new(0.0, 3.0, 1.0), Vec3::new(1.0, 3.0, 0.0)).unwrap();
+        assert_eq!(q, p);
+        assert!(Plane::from_points(Vec3::zero(), Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 2.0, 2.0)).is_none());
+        assert_eq!(Plane::from_vector4(Vector4::new(0.0, 0.0, 2.0, 4.0)), Plane::new(Vec3::new(0.0, 0.0, 1.0), 2.0));
+        assert_eq!(p.to_vector4(), Vector4::new(0.0, 1.0, 0.0, -3.0));
+    }
+
+    #[test]
+    fn plane_signed_distance_project() {
+        let p = Plane::from_point_normal(Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(p.signed_distance(Vec3::new(4.0, 2.0, 0.0)), 3.0);
+        assert_eq!(p.signed_distance(Vec3::new(-1.0, 2.0, 0.0)), -2.0);
+        assert_eq!(p.project_point(Vec3::new(4.0, 2.0, 7.0)), Vec3::new(1.0, 2.0, 7.0));
+    }
+
+    #[test]
+    fn plane_intersect_line() {
+        let p = Plane::new(Vec3::new(0.0, 0.0, 1.0), -2.0);
+        // Lines are infinite, so points on both sides hit
+        assert_eq!(p.intersect_line(Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 1.0)), Some(Vec3::new(1.0, 3.0, 2.0)));
+        assert_eq!(p.intersect_line(Vec3::new(1.0, 1.0, 5.0), Vec3::new(0.0, 1.0, 1.0)), Some(Vec3::new(1.0, -2.0, 2.0)));
+        assert!(p.intersect_line(Vec3::zero(), Vec3::new(1.0, 1.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn plane_intersect_planes() {
+        let x = Plane::from_point_normal(Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let y = Plane::from_point_normal(Vec3::new(0.0, -2.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+        let z = Plane::from_points(Vec3::new(0.0, 0.0, 3.0), Vec3::new(1.0, 0.0, 4.0), Vec3::new(0.0, 1.0, 3.0)).unwrap();
+        let p = Plane::intersect_planes(&x, &y, &z).unwrap();
+        assert!(p.approx_eq(&Vec3::new(1.0, -2.0, 4.0), EPS, 4));
+        assert!(Plane::intersect_planes(&x, &y, &x).is_none());
+    }
+
+    #[test]
+    fn matrix4_new_reflection() {
+        let p = Plane::from_point_normal(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        let m = Matrix4::new_reflection(&p);
+        let reflect = |v: Vec3| m.transform_point(Point3::from_vector(v)).to_vector();
+        assert_eq!(reflect(Vec3::new(1.0, 5.0, -1.0)), Vec3::new(1.0, -1.0, -1.0));
+        assert_eq!(reflect(Vec3::new(3.0, 2.0, 4.0)), Vec3::new(3.0, 2.0, 4.0));
+        assert_eq!(m * m, Matrix4::identity());
+
+        let q = Plane::from_point_normal(Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0));
+        let m = Matrix4::new_reflection(&q);
+        let v = m.transform_point(Point3::new(2.0, 1.0, 3.0)).to_vector();
+        assert!(v.approx_eq(&Vec3::new(0.0, -1.0, 3.0), EPS, 4));
+    }
+
+    #[test]
+    fn matrix4_new_planar_shadow() {
+        let ground = Plane::new(Vec3::new(0.0, 1.0, 0.0), 0.0);
+        let shadow = |light, v: Vec3| Matrix4::new_planar_shadow(&ground, light).transform_point(Point3::from_vector(v)).to_vector();
+        let point_light = Vector4::new(0.0, 10.0, 0.0, 1.0);
+        assert_eq!(shadow(point_light, Vec3::new(1.0, 5.0, -2.0)), Vec3::new(2.0, 0.0, -4.0));
+        let directional = Vector4::new(1.0, -1.0, 0.0, 0.0);
+        assert_eq!(shadow(directional, Vec3::new(0.0, 2.0, 3.0)), Vec3::new(2.0, 0.0, 3.0));
+        // Points on the plane stay in place
+        assert_eq!(shadow(point_light, Vec3::new(4.0, 0.0, 1.0)), Vec3::new(4.0, 0.0, 1.0));
+    }
+
+    // --------------------------------------------------------------------------
+    // Frustum
+    // --------------------------------------------------------------------------
+
+    // 90 degrees horizontal and vertical field of view, looking down -z from the origin.
+    fn perspective() -> Matrix4<f32> {
+        Matrix4::new_perspective_from_fov(90.0, 1.0, 1.0, 10.0)
+    }
+
+    #[test]
+    fn frustum_from_perspective() {
+        let f = Frustum::from_matrix(&perspective());
+        let h = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(f.left.approx_eq(&Plane::new(Vec3::new(h, 0.0, -h), 0.0), EPS, 4));
+        assert!(f.top.approx_eq(&Plane::new(Vec3::new(0.0, -h, -h), 0.0), EPS, 4));
+        assert!(f.near.approx_eq(&Plane::new(Vec3::new(0.0, 0.0, -1.0), -1.0), EPS, 4));
+        assert!(f.far.unwrap().approx_eq(&Plane::new(Vec3::new(0.0, 0.0, 1.0), 10.0), EPS, 4));
+    }
+
+    #[test]
+    fn frustum_infinite_far_plane() {
+        let view = Matrix4::new_look_at(Vec3::new(3.0, 1.0, 2.0), Vec3::new(-1.0, 0.5, -4.0), Vec3::new(0.0, 1.0, 0.0));
+        let f = Frustum::from_matrix(&(Matrix4::new_perspective_from_fov_with_clip_space(90.0, 1.0, 1.0, f32::INFINITY, ClipSpace::OPENGL) * view));
+        assert!(f.far.is_none(), "{:?}", f.far);
+        assert!(f.corners().is_none());
+        assert!(f.planes().all(|p| p.normal.length().approx_eq(&1.0, EPS, 4)));
+        assert_eq!(f.test_point(Vec3::new(-1e6, -0.5e6, -2e6)), Containment::Inside);
+        // Beyond the precision of T the far normal is rounding noise as well
+        let f = Frustum::from_matrix(&(Matrix4::new_perspective_from_fov(90.0, 1.0, 1.0, 1e7) * view));
+        assert!(f.far.is_none(), "{:?}", f.far);
+        let f = Frustum::from_matrix(&(Matrix4::new_perspective_from_fov(90.0, 1.0, 0.1, 1e4) * view));
+        assert!(f.far.is_some());
+    }
+
+    #[test]
+    fn frustum_corners() {
+        let corners = Frustum::from_matrix(&perspective()).corners().unwrap();
+        assert!(corners[0].approx_eq(&Vec3::new(-1.0, -1.0, -1.0), 1e-5, 4));
+        assert!(corners[3].approx_eq(&Vec3::new(1.0, 1.0, -1.0), 1e-5, 4));
+        assert!(corners[5].approx_eq(&Vec3::new(10.0, -10.0, -10.0), 1e-5, 4));
+        assert!(corners[6].approx_eq(&Vec3::new(-10.0, 10.0, -10.0), 1e-5, 4));
+
+        let ortho = Matrix4::new_orthographic_from_bounds(-2.0, 4.0, -1.0, 3.0, 0.5, 8.0);
+        let corners = Frustum::from_matrix(&ortho).corners().unwrap();
+        assert!(corners[0].approx_eq(&Vec3::new(-2.0, -1.0, -0.5), EPS, 4));
+        assert!(corners[7].approx_eq(&Vec3::new(4.0, 3.0, -8.0), EPS, 4));
+    }
+
+    #[test]
+    fn frustum_corners_view_projection() {
+        let view = Matrix4::new_look_at(Vec3::new(5.0, 0.0, 0.0), Vec3::new(5.0, 0.0, -1.0), Vec3::new(0.0, 1.0, 0.0));
+        let corners = Frustum::from_matrix(&(perspective() * view)).corners().unwrap();
+        assert!(corners[0].approx_eq(&Vec3::new(4.0, -1.0, -1.0), 1e-5, 4));
+        assert!(corners[7].approx_eq(&Vec3::new(15.0, 10.0, -10.0), 1e-5, 4));
+    }
+
+    #[test]
+    fn frustum_test_point() {
+        let f = Frustum::from_matrix(&perspective());
+        assert_eq!(f.test_point(Vec3::new(0.0, 0.0, -5.0)), Containment::Inside);
+        assert_eq!(f.test_point(Vec3::new(0.0, 0.0, -0.5)), Containment::Outside);
+        assert_eq!(f.test_point(Vec3::new(0.0, 0.0, -11.0)), Containment::Outside);
+        assert_eq!(f.test_point(Vec3::new(6.0, 0.0, -5.0)), Containment::Outside);
+        let ortho = Frustum::from_matrix(&Matrix4::new_orthographic_from_bounds(-2.0, 2.0, -2.0, 2.0, 1.0, 5.0));
+        assert_eq!(ortho.test_point(Vec3::new(2.0, 0.0, -3.0)), Containment::Intersecting);
+    }
+
+    #[test]
+    fn frustum_test_sphere() {
+        let f = Frustum::from_matrix(&perspective());
+        assert_eq!(f.test_sphere(Vec3::new(0.0, 0.0, -5.0), 1.0), Containment::Inside);
+        assert_eq!(f.test_sphere(Vec3::new(0.0, 0.0, -10.0), 1.0), Containment::Intersecting);
+        assert_eq!(f.test_sphere(Vec3::new(0.0, -5.0, -5.0), 0.5), Containment::Intersecting);
+        assert_eq!(f.test_sphere(Vec3::new(0.0, 8.0, -5.0), 1.0), Containment::Outside);
+        assert_eq!(f.test_sphere(Vec3::new(0.0, 0.0, 2.0), 1.0), Containment::Outside);
+    }
+
+    #[test]
+    fn frustum_test_aabb() {
+        let f = Frustum::from_matrix(&perspective());
+        let aabb = |min, max| Aabb3::new(min, max);
+        assert_eq!(f.test_aabb(&aabb(Vec3::new(-1.0, -1.0, -6.0), Vec3::new(1.0, 1.0, -4.0))), Containment::Inside);
+        assert_eq!(f.test_aabb(&aabb(Vec3::new(-1.0, -1.0, -12.0), Vec3::new(1.0, 1.0, -8.0))), Containment::Intersecting);
+        assert_eq!(f.test_aabb(&aabb(Vec3::new(7.0, -1.0, -6.0), Vec3::new(9.0, 1.0, -4.0))), Containment::Outside);
+        assert_eq!(f.test_aabb(&aabb(Vec3::new(-1.0, -1.0, 0.0), Vec3::new(1.0, 1.0, 2.0))), Containment::Outside);
+        // Enclosing the whole frustum
+        assert_eq!(f.test_aabb(&aabb(Vec3::new(-20.0, -20.0, -20.0), Vec3::new(20.0, 20.0, 20.0))), Containment::Intersecting);
+    }
+}
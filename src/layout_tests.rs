@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::vector::*;
+    use crate::vector_n::*;
+    use crate::matrix::*;
+    use crate::traits::*;
+
+    // These tests go through every array view of the vector and matrix types. They are meant to be
+    // run under Miri as well, like the rest of the suite:
+    //
+    //     MIRIFLAGS=-Zmiri-deterministic-floats cargo +nightly miri test
+    //
+    // Without the flag Miri adds noise to transcendental functions, which breaks tests comparing
+    // two separately computed sin/cos results within a few ULPs.
+
+    // --------------------------------------------------------------------------
+    // Vectors
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn layout_vector_as_array() {
+        assert_eq!(Vector2::new(1, 2).as_array(), &[1, 2]);
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0).as_array(), &[1.0, 2.0, 3.0]);
+        assert_eq!(Vector4::new(1u8, 2, 3, 4).as_array(), &[1, 2, 3, 4]);
+        assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).as_array(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(VectorN::new([1, 2, 3, 4, 5]).as_array(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn layout_vector_size() {
+        assert_eq!(std::mem::size_of::<Vector2<u8>>(), 2);
+        assert_eq!(std::mem::size_of::<Vector3<f32>>(), 12);
+        assert_eq!(std::mem::size_of::<Vector4<f64>>(), 32);
+        assert_eq!(std::mem::align_of::<Vector3<f32>>(), std::mem::align_of::<f32>());
+    }
+
+    // --------------------------------------------------------------------------
+    // Matrices
+    // --------------------------------------------------------------------------
+
+    // Fills the matrix with 0, 1, 2, ... in column-major order and checks all views against it.
+    macro_rules! test_matrix_layout {
+        ($name:ident, $type:ident, $rows:literal x $cols:literal) => {
+            #[test]
+            fn $name() {
+                let mut m = $type::<i32>::zero();
+                for c in 0..$cols {
+                    for r in 0..$rows {
+                        m.set_col_elem(c, r, (c*$rows + r) as i32);
+                    }
+                }
+                let expected: [i32; $rows*$cols] = std::array::from_fn(|i| i as i32);
+
+                assert_eq!(m.to_array(), expected);
+                assert_eq!((&m).as_array(), &expected);
+                assert_eq!((&mut m).as_array(), &expected);
+                for (c, col) in (&m).as_arrays().iter().enumerate() {
+                    assert_eq!(col, &std::array::from_fn::<i32, $rows, _>(|r| (c*$rows + r) as i32));
+                }
+
+                // Writes through the mutable views end up in the right elements
+                (&mut m).as_array()[$rows*$cols - 1] = -1;
+                assert_eq!(m.col_elem($cols - 1, $rows - 1), -1);
+                (&mut m).as_arrays()[$cols - 1][0] = -2;
+                assert_eq!(m.row_elem(0, $cols - 1), -2);
+                *(&mut m).col_elem_mut(0, $rows - 1) = -3;
+                assert_eq!(*(&m).col_elem_ref(0, $rows - 1), -3);
+            }
+        }
+    }
+
+    test_matrix_layout!(layout_matrix2, Matrix2, 2 x 2);
+    test_matrix_layout!(layout_matrix3, Matrix3, 3 x 3);
+    test_matrix_layout!(layout_matrix4, Matrix4, 4 x 4);
+    test_matrix_layout!(layout_matrix2x3, Matrix2x3, 2 x 3);
+    test_matrix_layout!(layout_matrix2x4, Matrix2x4, 2 x 4);
+    test_matrix_layout!(layout_matrix3x2, Matrix3x2, 3 x 2);
+    test_matrix_layout!(layout_matrix3x4, Matrix3x4, 3 x 4);
+    test_matrix_layout!(layout_matrix4x2, Matrix4x2, 4 x 2);
+    test_matrix_layout!(layout_matrix4x3, Matrix4x3, 4 x 3);
+
+    #[test]
+    fn layout_matrix_f32() {
+        let m = Matrix4::from_cols(
+            Vector4::new(1.0f32, 2.0, 3.0, 4.0),
+            Vector4::new(5.0, 6.0, 7.0, 8.0),
+            Vector4::new(9.0, 10.0, 11.0, 12.0),
+            Vector4::new(13.0, 14.0, 15.0, 16.0)
+        );
+        assert_eq!((&m).as_arrays()[2], [9.0, 10.0, 11.0, 12.0]);
+        assert_eq!(m.to_array()[15], 16.0);
+    }
+}
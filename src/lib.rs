@@ -1,10 +1,57 @@
 #[macro_use] mod macros;
 mod traits;
+mod backend;
+mod backend_tests;
+mod angle;
+mod angle_tests;
 mod vector;
 mod vector_tests;
+mod swizzle;
+mod swizzle_tests;
+mod vector_n;
+mod vector_n_tests;
+mod mask;
+mod mask_tests;
+mod wide;
+mod wide_tests;
 mod matrix;
 mod matrix_tests;
+mod matrix_n;
+mod matrix_n_tests;
+mod layout_tests;
+mod point;
+mod point_tests;
+mod affine;
+mod affine_tests;
+mod quaternion;
+mod quaternion_tests;
+mod dual_quaternion;
+mod dual_quaternion_tests;
+mod euler;
+mod euler_tests;
+mod decompose;
+mod decompose_tests;
+mod clip_space;
+mod clip_space_tests;
+mod geometry;
+mod geometry_tests;
+mod viewport;
+mod viewport_tests;
 
 pub use traits::*;
+pub use angle::*;
 pub use vector::*;
+pub use vector_n::*;
+pub use mask::*;
+pub use wide::*;
 pub use matrix::*;
+pub use matrix_n::*;
+pub use point::*;
+pub use affine::*;
+pub use quaternion::*;
+pub use dual_quaternion::*;
+pub use euler::*;
+pub use decompose::*;
+pub use clip_space::*;
+pub use geometry::*;
+pub use viewport::*;
@@ -4,23 +4,59 @@ macro_rules! implement_binary_assign_operator {
         fn $function:ident($lhs:ident, $rhs:ident) {
             $body:expr
         }
+    ) => {
+        implement_binary_assign_operator!([] $operator_trait<$type_rhs> for $type where T: Base,
+            fn $function($lhs, $rhs) {
+                $body
+            }
+        );
+    };
+    // With constraint
+    ($operator_trait:ident<$type_rhs:ty> for $type:ty where T: $contraint_trait:ident,
+        fn $function:ident($lhs:ident, $rhs:ident) {
+            $body:expr
+        }
+    ) => {
+        implement_binary_assign_operator!([] $operator_trait<$type_rhs> for $type where T: $contraint_trait,
+            fn $function($lhs, $rhs) {
+                $body
+            }
+        );
+    };
+    // With additional generic parameters (e.g. const generics)
+    ([$($generics:tt)*] $operator_trait:ident<$type_rhs:ty> for $type:ty,
+        fn $function:ident($lhs:ident, $rhs:ident) {
+            $body:expr
+        }
+    ) => {
+        implement_binary_assign_operator!([$($generics)*] $operator_trait<$type_rhs> for $type where T: Base,
+            fn $function($lhs, $rhs) {
+                $body
+            }
+        );
+    };
+    // With additional generic parameters and constraint
+    ([$($generics:tt)*] $operator_trait:ident<$type_rhs:ty> for $type:ty where T: $contraint_trait:ident,
+        fn $function:ident($lhs:ident, $rhs:ident) {
+            $body:expr
+        }
     ) => {
         // val
-        impl<T> $operator_trait<$type_rhs> for $type where T: Base {
+        impl<T, $($generics)*> $operator_trait<$type_rhs> for $type where T: $contraint_trait {
             fn $function(&mut self, other: $type_rhs) {
                 let ($lhs, $rhs) = (self, other); $body
             }
         }
 
         // &val
-        impl<'a, T> $operator_trait<&'a $type_rhs> for $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<&'a $type_rhs> for $type where T: $contraint_trait {
             fn $function(&mut self, other: &'a $type_rhs) {
                 let ($lhs, $rhs) = (self, *other); $body
             }
         }
 
         // &mut val
-        impl<'a, T> $operator_trait<&'a mut $type_rhs> for $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<&'a mut $type_rhs> for $type where T: $contraint_trait {
             fn $function(&mut self, other: &'a mut $type_rhs) {
                 let ($lhs, $rhs) = (self, *other); $body
             }
@@ -35,43 +71,67 @@ macro_rules! implement_unary_operator {
             $body:expr
         }
     ) => {
-        impl<T> $operator_trait for $type where T: Base + $contraint_trait {
+        implement_unary_operator!([] $operator_trait for $type where T: $contraint_trait,
+            fn $function($self) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With constraint that defines "Output"
+    ($operator_trait:ident for $type:ty where T: $contraint_trait:ident<Output=T>,
+        fn $function:ident($self:ident) -> $result_type:ty {
+            $body:expr
+        }
+    ) => {
+        implement_unary_operator!([] $operator_trait for $type where T: $contraint_trait<Output=T>,
+            fn $function($self) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With additional generic parameters and simple constraint
+    ([$($generics:tt)*] $operator_trait:ident for $type:ty where T: $contraint_trait:ident,
+        fn $function:ident($self:ident) -> $result_type:ty {
+            $body:expr
+        }
+    ) => {
+        impl<T, $($generics)*> $operator_trait for $type where T: Base + $contraint_trait {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
         }
 
-        impl<'a, T> $operator_trait for &'a $type where T: Base + $contraint_trait {
+        impl<'a, T, $($generics)*> $operator_trait for &'a $type where T: Base + $contraint_trait {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
         }
 
-        impl<'a, T> $operator_trait for &'a mut $type where T: Base + $contraint_trait {
+        impl<'a, T, $($generics)*> $operator_trait for &'a mut $type where T: Base + $contraint_trait {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
         }
     };
-    // With constraint that defines "Output"
-    ($operator_trait:ident for $type:ty where T: $contraint_trait:ident<Output=T>,
+    // With additional generic parameters and constraint that defines "Output"
+    ([$($generics:tt)*] $operator_trait:ident for $type:ty where T: $contraint_trait:ident<Output=T>,
         fn $function:ident($self:ident) -> $result_type:ty {
             $body:expr
         }
     ) => {
-        impl<T> $operator_trait for $type where T: Base + $contraint_trait<Output=T> {
+        impl<T, $($generics)*> $operator_trait for $type where T: Base + $contraint_trait<Output=T> {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
         }
 
-        impl<'a, T> $operator_trait for &'a $type where T: Base + $contraint_trait<Output=T> {
+        impl<'a, T, $($generics)*> $operator_trait for &'a $type where T: Base + $contraint_trait<Output=T> {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
         }
 
-        impl<'a, T> $operator_trait for &'a mut $type where T: Base + $contraint_trait<Output=T> {
+        impl<'a, T, $($generics)*> $operator_trait for &'a mut $type where T: Base + $contraint_trait<Output=T> {
             type Output = $type;
             #[inline]
             fn $function(self) -> $type { let $self = self; $body }
@@ -84,9 +144,45 @@ macro_rules! implement_binary_operator {
         fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
             $body:expr
         }
+    ) => {
+        implement_binary_operator!([] $operator_trait<$type_rhs> for $type where T: Base,
+            fn $function($lhs, $rhs) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With constraint
+    ($operator_trait:ident<$type_rhs:ty> for $type:ty where T: $contraint_trait:ident,
+        fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
+            $body:expr
+        }
+    ) => {
+        implement_binary_operator!([] $operator_trait<$type_rhs> for $type where T: $contraint_trait,
+            fn $function($lhs, $rhs) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With additional generic parameters (e.g. const generics)
+    ([$($generics:tt)*] $operator_trait:ident<$type_rhs:ty> for $type:ty,
+        fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
+            $body:expr
+        }
+    ) => {
+        implement_binary_operator!([$($generics)*] $operator_trait<$type_rhs> for $type where T: Base,
+            fn $function($lhs, $rhs) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With additional generic parameters and constraint
+    ([$($generics:tt)*] $operator_trait:ident<$type_rhs:ty> for $type:ty where T: $contraint_trait:ident,
+        fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
+            $body:expr
+        }
     ) => {
         // self op other
-        impl<T> $operator_trait<$type_rhs> for $type where T: Base {
+        impl<T, $($generics)*> $operator_trait<$type_rhs> for $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline] 
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -95,7 +191,7 @@ macro_rules! implement_binary_operator {
         }
 
         // self op &other
-        impl<'b, T> $operator_trait<&'b $type_rhs> for $type where T: Base {
+        impl<'b, T, $($generics)*> $operator_trait<&'b $type_rhs> for $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b $type_rhs) -> $result_type {
@@ -104,7 +200,7 @@ macro_rules! implement_binary_operator {
         }
 
         // self op &mut other
-        impl<'b, T> $operator_trait<&'b mut $type_rhs> for $type where T: Base {
+        impl<'b, T, $($generics)*> $operator_trait<&'b mut $type_rhs> for $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b mut $type_rhs) -> $result_type {
@@ -113,7 +209,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &self op other
-        impl<'a, T> $operator_trait<$type_rhs> for &'a $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<$type_rhs> for &'a $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -122,7 +218,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &self op &other
-        impl<'a, T> $operator_trait<&'a $type_rhs> for &'a $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<&'a $type_rhs> for &'a $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'a $type_rhs) -> $result_type {
@@ -131,7 +227,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &self op &mut other
-        impl<'a, T> $operator_trait<&'a mut $type_rhs> for &'a $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<&'a mut $type_rhs> for &'a $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'a mut $type_rhs) -> $result_type {
@@ -140,7 +236,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &mut self op other
-        impl<'a, T> $operator_trait<$type_rhs> for &'a mut $type where T: Base {
+        impl<'a, T, $($generics)*> $operator_trait<$type_rhs> for &'a mut $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -149,7 +245,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &mut self op &other
-        impl<'a, 'b, T> $operator_trait<&'b $type_rhs> for &'a mut $type where T: Base {
+        impl<'a, 'b, T, $($generics)*> $operator_trait<&'b $type_rhs> for &'a mut $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b $type_rhs) -> $result_type {
@@ -158,7 +254,7 @@ macro_rules! implement_binary_operator {
         }
 
         // &mut self op &mut other
-        impl<'a, 'b, T> $operator_trait<&'b mut $type_rhs> for &'a mut $type where T: Base {
+        impl<'a, 'b, T, $($generics)*> $operator_trait<&'b mut $type_rhs> for &'a mut $type where T: $contraint_trait {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b mut $type_rhs) -> $result_type {
@@ -173,9 +269,21 @@ macro_rules! implement_binary_operator_non_generic {
         fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
             $body:expr
         }
+    ) => {
+        implement_binary_operator_non_generic!([] $operator_trait<$type_rhs> for $type,
+            fn $function($lhs, $rhs) -> $result_type {
+                $body
+            }
+        );
+    };
+    // With generic parameters that do not involve T (e.g. const generics)
+    ([$($generics:tt)*] $operator_trait:ident<$type_rhs:ty> for $type:ty,
+        fn $function:ident($lhs:ident, $rhs:ident) -> $result_type:ty {
+            $body:expr
+        }
     ) => {
         // self op other
-        impl $operator_trait<$type_rhs> for $type {
+        impl<$($generics)*> $operator_trait<$type_rhs> for $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -184,7 +292,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // self op &other
-        impl<'b> $operator_trait<&'b $type_rhs> for $type {
+        impl<'b, $($generics)*> $operator_trait<&'b $type_rhs> for $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b $type_rhs) -> $result_type {
@@ -193,7 +301,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // self op &mut other
-        impl<'b> $operator_trait<&'b mut $type_rhs> for $type {
+        impl<'b, $($generics)*> $operator_trait<&'b mut $type_rhs> for $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b mut $type_rhs) -> $result_type {
@@ -202,7 +310,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &self op other
-        impl<'a> $operator_trait<$type_rhs> for &'a $type {
+        impl<'a, $($generics)*> $operator_trait<$type_rhs> for &'a $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -211,7 +319,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &self op &other
-        impl<'a, 'b> $operator_trait<&'b $type_rhs> for &'a $type {
+        impl<'a, 'b, $($generics)*> $operator_trait<&'b $type_rhs> for &'a $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b $type_rhs) -> $result_type {
@@ -220,7 +328,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &self op &mut other
-        impl<'a, 'b> $operator_trait<&'b mut $type_rhs> for &'a $type {
+        impl<'a, 'b, $($generics)*> $operator_trait<&'b mut $type_rhs> for &'a $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b mut $type_rhs) -> $result_type {
@@ -229,7 +337,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &mut self op other
-        impl<'a> $operator_trait<$type_rhs> for &'a mut $type {
+        impl<'a, $($generics)*> $operator_trait<$type_rhs> for &'a mut $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: $type_rhs) -> $result_type {
@@ -238,7 +346,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &mut self op &other
-        impl<'a, 'b> $operator_trait<&'b $type_rhs> for &'a mut $type {
+        impl<'a, 'b, $($generics)*> $operator_trait<&'b $type_rhs> for &'a mut $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b $type_rhs) -> $result_type {
@@ -247,7 +355,7 @@ macro_rules! implement_binary_operator_non_generic {
         }
 
         // &mut self op &mut other
-        impl<'a, 'b> $operator_trait<&'b mut $type_rhs> for &'a mut $type {
+        impl<'a, 'b, $($generics)*> $operator_trait<&'b mut $type_rhs> for &'a mut $type {
             type Output = $result_type;
             #[inline]
             fn $function(self, other: &'b mut $type_rhs) -> $result_type {
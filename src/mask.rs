@@ -0,0 +1,123 @@
+use std::{
+    array,
+    ops::{BitAnd,BitAndAssign,BitOr,BitOrAssign,BitXor,BitXorAssign,Not},
+};
+
+use crate::vector_n::{serialize_array, deserialize_array};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// One boolean per lane of a wide type. Produced by lane-wise comparisons and consumed by select.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mask<const N: usize> {
+    pub lanes: [bool; N],
+}
+
+// The masks produced by comparing Vector2/3/4.
+pub type BVec2 = Mask<2>;
+pub type BVec3 = Mask<3>;
+pub type BVec4 = Mask<4>;
+
+impl<const N: usize> Mask<N> {
+    pub fn new(lanes: [bool; N]) -> Self {
+        Mask { lanes }
+    }
+
+    pub fn splat(value: bool) -> Self {
+        Mask::new([value; N])
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> bool>(f: F) -> Self {
+        Mask::new(array::from_fn(f))
+    }
+
+    #[inline]
+    pub fn lanes() -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn test(&self, lane: usize) -> bool {
+        self.lanes[lane]
+    }
+
+    #[inline]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        self.lanes[lane] = value;
+    }
+
+    pub fn any(&self) -> bool {
+        self.lanes.iter().any(|&b| b)
+    }
+
+    pub fn all(&self) -> bool {
+        self.lanes.iter().all(|&b| b)
+    }
+
+    pub fn none(&self) -> bool {
+        !self.any()
+    }
+
+    pub fn count(&self) -> usize {
+        self.lanes.iter().filter(|&&b| b).count()
+    }
+
+    // Bit i is set if lane i is set. Only the first 64 lanes are taken into account.
+    pub fn bitmask(&self) -> u64 {
+        self.lanes.iter().take(64).enumerate().fold(0, |bits, (i, &b)| bits | ((b as u64) << i))
+    }
+}
+
+impl<const N: usize> Default for Mask<N> {
+    fn default() -> Self {
+        Mask::splat(false)
+    }
+}
+
+macro_rules! implement_mask_operator {
+    ($operator_trait:ident, $function:ident, $assign_trait:ident, $assign_function:ident, $op:tt) => {
+        impl<const N: usize> $operator_trait for Mask<N> {
+            type Output = Mask<N>;
+            #[inline]
+            fn $function(self, other: Mask<N>) -> Mask<N> {
+                Mask::from_fn(|i| self.lanes[i] $op other.lanes[i])
+            }
+        }
+
+        impl<const N: usize> $assign_trait for Mask<N> {
+            #[inline]
+            fn $assign_function(&mut self, other: Mask<N>) {
+                *self = *self $op other;
+            }
+        }
+    }
+}
+
+implement_mask_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+implement_mask_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+implement_mask_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl<const N: usize> Not for Mask<N> {
+    type Output = Mask<N>;
+    #[inline]
+    fn not(self) -> Mask<N> {
+        Mask::from_fn(|i| !self.lanes[i])
+    }
+}
+
+// --------------------------------------------------------------------------
+// Serde
+// --------------------------------------------------------------------------
+
+// Written as a tuple of its lanes like VectorN.
+impl<const N: usize> Serialize for Mask<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self.lanes, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Mask<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer, false).map(Mask::new)
+    }
+}
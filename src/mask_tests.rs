@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::mask::*;
+
+    #[test]
+    fn mask_any_all_none() {
+        let m = Mask::new([true, false, true, false]);
+        assert!(m.any() && !m.all() && !m.none());
+        assert!(Mask::<8>::splat(true).all());
+        assert!(Mask::<8>::default().none());
+        assert_eq!(m.count(), 2);
+        assert_eq!(Mask::<4>::lanes(), 4);
+    }
+
+    #[test]
+    fn mask_bitmask() {
+        assert_eq!(Mask::new([true, false, true, true]).bitmask(), 0b1101);
+        assert_eq!(Mask::<8>::splat(true).bitmask(), 0xff);
+    }
+
+    #[test]
+    fn mask_test_set() {
+        let mut m = Mask::<4>::default();
+        m.set(2, true);
+        assert!(m.test(2) && !m.test(1));
+        assert_eq!(Mask::from_fn(|i| i == 2), m);
+    }
+
+    #[test]
+    fn mask_operators() {
+        let a = Mask::new([true, true, false, false]);
+        let b = Mask::new([true, false, true, false]);
+        assert_eq!(a & b, Mask::new([true, false, false, false]));
+        assert_eq!(a | b, Mask::new([true, true, true, false]));
+        assert_eq!(a ^ b, Mask::new([false, true, true, false]));
+        assert_eq!(!a, Mask::new([false, false, true, true]));
+        let mut c = a;
+        c &= b;
+        c |= Mask::new([false, false, false, true]);
+        c ^= Mask::splat(true);
+        assert_eq!(c, Mask::new([false, true, true, false]));
+    }
+}
@@ -1,11 +1,14 @@
 use std::{
     ops::{Mul,Neg},
-    mem,
+    error,
+    fmt,
 };
 
 use crate::{
     traits::*,
+    backend,
     vector::*,
+    angle::*,
 };
 
 use serde::{Serialize, Deserialize};
@@ -14,6 +17,7 @@ pub use float_cmp::{Ulps,ApproxEq};
 
 macro_rules! implement_one_to_one_assign_method {
     (fn $method_name:ident -> $type:ident { $($member:ident),* }) => {
+        #[allow(clippy::too_many_arguments)]
         pub fn $method_name($($member: T),*) -> Self {
             $type { $($member: $member),* }
         }   
@@ -22,8 +26,11 @@ macro_rules! implement_one_to_one_assign_method {
 
 macro_rules! implement_matrix {
     ($matrix_type:ident {
-        dim: $dim:expr,
-        vector_type: $vector_type:ident,
+        shape: $rows:literal x $cols:literal,
+        col_vector_type: $col_vector_type:ident,
+        row_vector_type: $row_vector_type:ident,
+        transpose_type: $transpose_type:ident,
+        kernels: $kernels:ident,
         cols: { 
             $($c_num:expr => $c:ident { $($ce_num:expr => $m_col_element:ident: $col_vec_member:ident),*}),*
         },
@@ -38,11 +45,6 @@ macro_rules! implement_matrix {
         }
 
         impl<T: Base> $matrix_type<T> {
-            #[inline]
-            pub fn dims() -> usize {
-                ($({let $c = 1; $c} +)* 0)
-            }
-
             implement_one_to_one_assign_method!(
                 fn from_components_row_major -> $matrix_type { 
                     $($($m_row_element),*),*
@@ -55,38 +57,30 @@ macro_rules! implement_matrix {
                 }
             );
 
-            pub fn from_cols($($c: $vector_type<T>),*) -> Self {
+            pub fn from_cols($($c: $col_vector_type<T>),*) -> Self {
                 $matrix_type::from_components_col_major($($($c.$col_vec_member),*),*)
             }
 
-            pub fn from_rows($($r: $vector_type<T>),*) -> Self {
+            pub fn from_rows($($r: $row_vector_type<T>),*) -> Self {
                 $matrix_type::from_components_row_major($($($r.$row_vec_member),*),*)
             }
 
-            pub fn transpose(&self) -> Self {
-                $matrix_type::from_components_row_major($($(self.$m_col_element),*),*)
-            }
-
-            pub fn col(&self, index: usize) -> $vector_type<T> {
+            pub fn col(&self, index: usize) -> $col_vector_type<T> {
                 match index {
-                    $($c_num => $vector_type::new( $(self.$m_col_element),* ),)*
+                    $($c_num => $col_vector_type::new( $(self.$m_col_element),* ),)*
                     _ => panic!("index out of range"),
                 }
             }
 
-            pub fn row(&self, index: usize) -> $vector_type<T> {
+            pub fn row(&self, index: usize) -> $row_vector_type<T> {
                 match index {
-                    $($r_num => $vector_type::new( $(self.$m_row_element),* ),)*
+                    $($r_num => $row_vector_type::new( $(self.$m_row_element),* ),)*
                     _ => panic!("index out of range")
                 }
             }
 
             pub fn set_col_elem(&mut self, col_index: usize, elem_index: usize, value: T) {
-                self.as_array()[col_index*$dim + elem_index] = value;
-            }
-
-            pub fn identity() -> Self {
-                $matrix_type::from_diagonal($vector_type::one())
+                self.as_array()[col_index*$rows + elem_index] = value;
             }
 
             fn from_value(value: T) -> Self {
@@ -102,66 +96,53 @@ macro_rules! implement_matrix {
             }
 
             #[inline]
-            pub fn to_array(&self) -> [T; $dim*$dim] {
-                unsafe { mem::transmute_copy(self) }
+            pub fn to_array(&self) -> [T; $rows*$cols] {
+                *self.as_array()
             }
         }
 
-        // m * m
-        implement_binary_operator!(Mul<$matrix_type<T>> for $matrix_type<T>,
-            fn mul(lhs, rhs) -> $matrix_type<T> {
-                unsafe {
-                    let mut res: $matrix_type<T> = mem::uninitialized();
-                    for r in 0..$dim {
-                        for c in 0..$dim {
-                            let col = rhs.col(c);
-                            let row = lhs.row(r);
-                            res.set_col_elem(c, r, col.dot(row));
-                        }
-                    }
-                    res
-                }
-            }
-        );
-
-        // m * v
-        implement_binary_operator!(Mul<$vector_type<T>> for $matrix_type<T>,
-            fn mul(lhs, rhs) -> $vector_type<T> {
-                $vector_type::new(
-                    $(lhs.row($r_num).dot(rhs)),*
-                )
-            }
-        );
+        implement_matrix_kernels!($kernels $matrix_type {
+            col_vector_type: $col_vector_type,
+            row_vector_type: $row_vector_type,
+            transpose_type: $transpose_type,
+            rows: { $($r_num),* },
+            elems: { $($($m_col_element),*),* }
+        });
 
         impl<'a, T: Base> AsArray for &'a $matrix_type<T> {
-            type Output = &'a [T; $dim*$dim];
+            type Output = &'a [T; $rows*$cols];
             #[inline]
             fn as_array(self) -> Self::Output {
-                unsafe { mem::transmute(self) }
+                // SAFETY: The type is #[repr(C)] and all fields are of type T, so it has the same
+                // layout as an array of its elements in column-major order.
+                unsafe { &*(self as *const $matrix_type<T> as *const [T; $rows*$cols]) }
             }
         }
 
         impl<'a, T: Base> AsArray for &'a mut $matrix_type<T> {
-            type Output = &'a mut [T; $dim*$dim];
+            type Output = &'a mut [T; $rows*$cols];
             #[inline]
             fn as_array(self) -> Self::Output {
-                unsafe { mem::transmute(self) }
+                // SAFETY: See above.
+                unsafe { &mut *(self as *mut $matrix_type<T> as *mut [T; $rows*$cols]) }
             }
         }
 
         impl<'a, T: Base> AsArrays for &'a $matrix_type<T> {
-            type Output = &'a [[T; $dim]; $dim];
+            type Output = &'a [[T; $rows]; $cols];
             #[inline]
             fn as_arrays(self) -> Self::Output {
-                unsafe { mem::transmute(self) }
+                // SAFETY: Arrays have no padding, so [[T; $rows]; $cols] is laid out like [T; $rows*$cols].
+                unsafe { &*(self as *const $matrix_type<T> as *const [[T; $rows]; $cols]) }
             }
         }
 
         impl<'a, T: Base> AsArrays for &'a mut $matrix_type<T> {
-            type Output = &'a mut [[T; $dim]; $dim];
+            type Output = &'a mut [[T; $rows]; $cols];
             #[inline]
             fn as_arrays(self) -> Self::Output {
-                unsafe { mem::transmute(self) }
+                // SAFETY: See above.
+                unsafe { &mut *(self as *mut $matrix_type<T> as *mut [[T; $rows]; $cols]) }
             }
         }
 
@@ -169,11 +150,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem(self, col_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[col_index*$dim + elem_index]
+                self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem(self, row_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[elem_index*$dim + row_index]
+                self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -181,11 +162,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem(self, col_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[col_index*$dim + elem_index]
+                self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem(self, row_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[elem_index*$dim + row_index]
+                self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -193,11 +174,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem(self, col_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[col_index*$dim + elem_index]
+                self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem(self, row_index: usize, elem_index: usize) -> Self::Output {
-                self.as_array()[elem_index*$dim + row_index]
+                self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -205,11 +186,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem_ref(self, col_index: usize, elem_index: usize) -> &'a Self::Output {
-                &self.as_array()[col_index*$dim + elem_index]
+                &self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem_ref(self, row_index: usize, elem_index: usize) -> &'a Self::Output {
-                &self.as_array()[elem_index*$dim + row_index]
+                &self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -217,11 +198,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem_ref(self, col_index: usize, elem_index: usize) -> &'a Self::Output {
-                &self.as_array()[col_index*$dim + elem_index]
+                &self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem_ref(self, row_index: usize, elem_index: usize) -> &'a Self::Output {
-                &self.as_array()[elem_index*$dim + row_index]
+                &self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -229,11 +210,11 @@ macro_rules! implement_matrix {
             type Output = T;
             #[inline]
             fn col_elem_mut(self, col_index: usize, elem_index: usize) -> &'a mut Self::Output {
-                &mut self.as_array()[col_index*$dim + elem_index]
+                &mut self.as_array()[col_index*$rows + elem_index]
             }
             #[inline]
             fn row_elem_mut(self, row_index: usize, elem_index: usize) -> &'a mut Self::Output {
-                &mut self.as_array()[elem_index*$dim + row_index]
+                &mut self.as_array()[elem_index*$rows + row_index]
             }
         }
 
@@ -244,7 +225,7 @@ macro_rules! implement_matrix {
             #[inline]
             fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
                 //$($(self.$m_col_element.approx_eq(&other.$m_col_element, epsilon, ulps))&&*)&&*
-                for i in 0..$dim*$dim {
+                for i in 0..$rows*$cols {
                     if !self.as_array()[i].approx_eq(&other.as_array()[i], epsilon, ulps) {
                         return false;
                     }
@@ -255,11 +236,95 @@ macro_rules! implement_matrix {
     }
 }
 
+// Transposition and matrix-vector multiplication. With "backend" the operations are dispatched
+// to the backend module by hand-written impls instead (see Matrix4).
+macro_rules! implement_matrix_kernels {
+    (scalar $matrix_type:ident {
+        col_vector_type: $col_vector_type:ident,
+        row_vector_type: $row_vector_type:ident,
+        transpose_type: $transpose_type:ident,
+        rows: { $($r_num:expr),* },
+        elems: { $($m_col_element:ident),* }
+    }) => {
+        impl<T: Base> $matrix_type<T> {
+            pub fn transpose(&self) -> $transpose_type<T> {
+                $transpose_type::from_components_row_major($(self.$m_col_element),*)
+            }
+        }
+
+        // m * v
+        implement_binary_operator!(Mul<$row_vector_type<T>> for $matrix_type<T>,
+            fn mul(lhs, rhs) -> $col_vector_type<T> {
+                $col_vector_type::new(
+                    $(lhs.row($r_num).dot(rhs)),*
+                )
+            }
+        );
+    };
+    (backend $matrix_type:ident { $($ignored:tt)* }) => {};
+}
+
+macro_rules! implement_square_matrix {
+    ($matrix_type:ident {
+        dim: $dim:expr,
+        vector_type: $vector_type:ident,
+        kernels: scalar
+    }) => {
+        implement_square_matrix!($matrix_type { dim: $dim, vector_type: $vector_type, kernels: backend });
+
+        // m * m
+        implement_binary_operator!(Mul<$matrix_type<T>> for $matrix_type<T>,
+            fn mul(lhs, rhs) -> $matrix_type<T> {{
+                let mut res = $matrix_type::zero();
+                for r in 0..$dim {
+                    for c in 0..$dim {
+                        let col = rhs.col(c);
+                        let row = lhs.row(r);
+                        res.set_col_elem(c, r, col.dot(row));
+                    }
+                }
+                res
+            }}
+        );
+    };
+    ($matrix_type:ident {
+        dim: $dim:expr,
+        vector_type: $vector_type:ident,
+        kernels: backend
+    }) => {
+        impl<T: Base> $matrix_type<T> {
+            #[inline]
+            pub fn dims() -> usize {
+                $dim
+            }
+
+            pub fn identity() -> Self {
+                $matrix_type::from_diagonal($vector_type::one())
+            }
+        }
+    };
+}
+
+// Multiplication of matrices with matching inner dimension. The result is assembled column by
+// column from the products of the left matrix with the columns of the right matrix.
+macro_rules! implement_matrix_mul {
+    ($lhs_type:ident * $rhs_type:ident = $result_type:ident { cols: $($c_num:expr),+ }) => {
+        implement_binary_operator!(Mul<$rhs_type<T>> for $lhs_type<T>,
+            fn mul(lhs, rhs) -> $result_type<T> {
+                $result_type::from_cols( $(lhs * rhs.col($c_num)),+ )
+            }
+        );
+    }
+}
+
 implement_matrix!(
     Matrix2 {
-        dim: 2,
-        vector_type: Vector2,
-        cols: {  
+        shape: 2 x 2,
+        col_vector_type: Vector2,
+        row_vector_type: Vector2,
+        transpose_type: Matrix2,
+        kernels: scalar,
+        cols: {
             0 => c0 { 0 => m00: x, 1 => m10: y },
             1 => c1 { 0 => m01: x, 1 => m11: y }
         },
@@ -272,8 +337,11 @@ implement_matrix!(
 
 implement_matrix!(
     Matrix3 {
-        dim: 3,
-        vector_type: Vector3,
+        shape: 3 x 3,
+        col_vector_type: Vector3,
+        row_vector_type: Vector3,
+        transpose_type: Matrix3,
+        kernels: scalar,
         cols: {
             0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z },
             1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z },
@@ -289,8 +357,11 @@ implement_matrix!(
 
 implement_matrix!(
     Matrix4 {
-        dim: 4,
-        vector_type: Vector4,
+        shape: 4 x 4,
+        col_vector_type: Vector4,
+        row_vector_type: Vector4,
+        transpose_type: Matrix4,
+        kernels: backend,
         cols: {
             0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z, 3 => m30: w },
             1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z, 3 => m31: w },
@@ -306,6 +377,176 @@ implement_matrix!(
     }
 );
 
+// Rectangular matrices. MatrixRxC has R rows and C columns.
+implement_matrix!(
+    Matrix2x3 {
+        shape: 2 x 3,
+        col_vector_type: Vector2,
+        row_vector_type: Vector3,
+        transpose_type: Matrix3x2,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y },
+            1 => c1 { 0 => m01: x, 1 => m11: y },
+            2 => c2 { 0 => m02: x, 1 => m12: y }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y, 2 => m02: z },
+            1 => r1 { 0 => m10: x, 1 => m11: y, 2 => m12: z }
+        }
+    }
+);
+
+implement_matrix!(
+    Matrix2x4 {
+        shape: 2 x 4,
+        col_vector_type: Vector2,
+        row_vector_type: Vector4,
+        transpose_type: Matrix4x2,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y },
+            1 => c1 { 0 => m01: x, 1 => m11: y },
+            2 => c2 { 0 => m02: x, 1 => m12: y },
+            3 => c3 { 0 => m03: x, 1 => m13: y }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y, 2 => m02: z, 3 => m03: w },
+            1 => r1 { 0 => m10: x, 1 => m11: y, 2 => m12: z, 3 => m13: w }
+        }
+    }
+);
+
+implement_matrix!(
+    Matrix3x2 {
+        shape: 3 x 2,
+        col_vector_type: Vector3,
+        row_vector_type: Vector2,
+        transpose_type: Matrix2x3,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z },
+            1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y },
+            1 => r1 { 0 => m10: x, 1 => m11: y },
+            2 => r2 { 0 => m20: x, 1 => m21: y }
+        }
+    }
+);
+
+implement_matrix!(
+    Matrix3x4 {
+        shape: 3 x 4,
+        col_vector_type: Vector3,
+        row_vector_type: Vector4,
+        transpose_type: Matrix4x3,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z },
+            1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z },
+            2 => c2 { 0 => m02: x, 1 => m12: y, 2 => m22: z },
+            3 => c3 { 0 => m03: x, 1 => m13: y, 2 => m23: z }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y, 2 => m02: z, 3 => m03: w },
+            1 => r1 { 0 => m10: x, 1 => m11: y, 2 => m12: z, 3 => m13: w },
+            2 => r2 { 0 => m20: x, 1 => m21: y, 2 => m22: z, 3 => m23: w }
+        }
+    }
+);
+
+implement_matrix!(
+    Matrix4x2 {
+        shape: 4 x 2,
+        col_vector_type: Vector4,
+        row_vector_type: Vector2,
+        transpose_type: Matrix2x4,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z, 3 => m30: w },
+            1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z, 3 => m31: w }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y },
+            1 => r1 { 0 => m10: x, 1 => m11: y },
+            2 => r2 { 0 => m20: x, 1 => m21: y },
+            3 => r3 { 0 => m30: x, 1 => m31: y }
+        }
+    }
+);
+
+implement_matrix!(
+    Matrix4x3 {
+        shape: 4 x 3,
+        col_vector_type: Vector4,
+        row_vector_type: Vector3,
+        transpose_type: Matrix3x4,
+        kernels: scalar,
+        cols: {
+            0 => c0 { 0 => m00: x, 1 => m10: y, 2 => m20: z, 3 => m30: w },
+            1 => c1 { 0 => m01: x, 1 => m11: y, 2 => m21: z, 3 => m31: w },
+            2 => c2 { 0 => m02: x, 1 => m12: y, 2 => m22: z, 3 => m32: w }
+        },
+        rows: {
+            0 => r0 { 0 => m00: x, 1 => m01: y, 2 => m02: z },
+            1 => r1 { 0 => m10: x, 1 => m11: y, 2 => m12: z },
+            2 => r2 { 0 => m20: x, 1 => m21: y, 2 => m22: z },
+            3 => r3 { 0 => m30: x, 1 => m31: y, 2 => m32: z }
+        }
+    }
+);
+
+implement_square_matrix!(Matrix2 { dim: 2, vector_type: Vector2, kernels: scalar });
+implement_square_matrix!(Matrix3 { dim: 3, vector_type: Vector3, kernels: scalar });
+implement_square_matrix!(Matrix4 { dim: 4, vector_type: Vector4, kernels: backend });
+
+impl<T: Base> Matrix4<T> {
+    pub fn transpose(&self) -> Matrix4<T> {
+        backend::matrix4_transpose(*self)
+    }
+}
+
+// m * v
+implement_binary_operator!(Mul<Vector4<T>> for Matrix4<T>,
+    fn mul(lhs, rhs) -> Vector4<T> {
+        backend::matrix4_mul_vector4(lhs, rhs)
+    }
+);
+
+// m * m
+implement_binary_operator!(Mul<Matrix4<T>> for Matrix4<T>,
+    fn mul(lhs, rhs) -> Matrix4<T> {
+        backend::matrix4_mul(lhs, rhs)
+    }
+);
+
+implement_matrix_mul!(Matrix2 * Matrix2x3 = Matrix2x3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix2 * Matrix2x4 = Matrix2x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix2x3 * Matrix3x2 = Matrix2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix2x3 * Matrix3 = Matrix2x3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix2x3 * Matrix3x4 = Matrix2x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix2x4 * Matrix4x2 = Matrix2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix2x4 * Matrix4x3 = Matrix2x3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix2x4 * Matrix4 = Matrix2x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix3x2 * Matrix2 = Matrix3x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix3x2 * Matrix2x3 = Matrix3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix3x2 * Matrix2x4 = Matrix3x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix3 * Matrix3x2 = Matrix3x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix3 * Matrix3x4 = Matrix3x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix3x4 * Matrix4x2 = Matrix3x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix3x4 * Matrix4x3 = Matrix3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix3x4 * Matrix4 = Matrix3x4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix4x2 * Matrix2 = Matrix4x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix4x2 * Matrix2x3 = Matrix4x3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix4x2 * Matrix2x4 = Matrix4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix4x3 * Matrix3x2 = Matrix4x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix4x3 * Matrix3 = Matrix4x3 { cols: 0, 1, 2 });
+implement_matrix_mul!(Matrix4x3 * Matrix3x4 = Matrix4 { cols: 0, 1, 2, 3 });
+implement_matrix_mul!(Matrix4 * Matrix4x2 = Matrix4x2 { cols: 0, 1 });
+implement_matrix_mul!(Matrix4 * Matrix4x3 = Matrix4x3 { cols: 0, 1, 2 });
+
 impl<T: Base> Matrix2<T> {
     pub fn from_diagonal(d: Vector2<T>) -> Self {
         Matrix2::from_components_row_major(
@@ -336,6 +577,157 @@ impl<T: Base> Matrix4<T> {
     }
 }
 
+// Bulk versions of transform_point and transform_vector (see point.rs) for slices of vectors.
+impl<T: Base> Matrix3<T> {
+    // Transforms every point of src into dst like transform_point. The perspective divide is
+    // skipped if the last row is (0, 0, 1).
+    //
+    // Panics if src and dst differ in length, like slice::copy_from_slice.
+    pub fn transform_points(&self, src: &[Vector2<T>], dst: &mut [Vector2<T>]) {
+        assert_eq!(src.len(), dst.len(), "source and destination differ in length");
+        let (m, affine) = (*self, self.is_affine());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = m.transform_point_raw(*s, affine);
+        }
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vector2<T>]) {
+        let (m, affine) = (*self, self.is_affine());
+        for p in points.iter_mut() {
+            *p = m.transform_point_raw(*p, affine);
+        }
+    }
+
+    // Transforms every direction of src into dst like transform_vector.
+    //
+    // Panics if src and dst differ in length, like slice::copy_from_slice.
+    pub fn transform_vectors(&self, src: &[Vector2<T>], dst: &mut [Vector2<T>]) {
+        assert_eq!(src.len(), dst.len(), "source and destination differ in length");
+        let m = *self;
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = m.transform_vector(*s);
+        }
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vector2<T>]) {
+        let m = *self;
+        for v in vectors.iter_mut() {
+            *v = m.transform_vector(*v);
+        }
+    }
+
+    fn is_affine(&self) -> bool {
+        self.m20 == T::zero() && self.m21 == T::zero() && self.m22 == T::one()
+    }
+
+    #[inline]
+    fn transform_point_raw(&self, p: Vector2<T>, affine: bool) -> Vector2<T> {
+        let v = Vector2::new(
+            self.m00 * p.x + self.m01 * p.y + self.m02,
+            self.m10 * p.x + self.m11 * p.y + self.m12,
+        );
+        if affine {
+            v
+        } else {
+            v / (self.m20 * p.x + self.m21 * p.y + self.m22)
+        }
+    }
+}
+
+// The Matrix4 versions transform four vectors at a time as a single matrix product, which the
+// backend vectorizes for f32. The remaining vectors go through the matrix-vector product.
+impl<T: Base> Matrix4<T> {
+    // Transforms every point of src into dst like transform_point, e.g. the positions of a vertex
+    // buffer. The perspective divide is skipped if the last row is (0, 0, 0, 1).
+    //
+    // Panics if src and dst differ in length, like slice::copy_from_slice.
+    pub fn transform_points(&self, src: &[Vector3<T>], dst: &mut [Vector3<T>]) {
+        assert_eq!(src.len(), dst.len(), "source and destination differ in length");
+        self.transform_bulk(src, dst, T::one(), !self.is_affine());
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vector3<T>]) {
+        self.transform_bulk_in_place(points, T::one(), !self.is_affine());
+    }
+
+    // Transforms every direction of src into dst like transform_vector, e.g. the normals of a
+    // vertex buffer.
+    //
+    // Panics if src and dst differ in length, like slice::copy_from_slice.
+    pub fn transform_vectors(&self, src: &[Vector3<T>], dst: &mut [Vector3<T>]) {
+        assert_eq!(src.len(), dst.len(), "source and destination differ in length");
+        self.transform_bulk(src, dst, T::zero(), false);
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vector3<T>]) {
+        self.transform_bulk_in_place(vectors, T::zero(), false);
+    }
+
+    fn is_affine(&self) -> bool {
+        self.m30 == T::zero() && self.m31 == T::zero() && self.m32 == T::zero() && self.m33 == T::one()
+    }
+
+    // w is 1 for points and 0 for directions.
+    fn transform_bulk(&self, src: &[Vector3<T>], dst: &mut [Vector3<T>], w: T, divide: bool) {
+        let mut src = src.chunks_exact(4);
+        let mut dst = dst.chunks_exact_mut(4);
+        for (d, s) in (&mut dst).zip(&mut src) {
+            d.copy_from_slice(&self.transform4([s[0], s[1], s[2], s[3]], w, divide));
+        }
+        for (d, s) in dst.into_remainder().iter_mut().zip(src.remainder()) {
+            *d = self.transform1(*s, w, divide);
+        }
+    }
+
+    fn transform_bulk_in_place(&self, vectors: &mut [Vector3<T>], w: T, divide: bool) {
+        let mut chunks = vectors.chunks_exact_mut(4);
+        for c in &mut chunks {
+            let res = self.transform4([c[0], c[1], c[2], c[3]], w, divide);
+            c.copy_from_slice(&res);
+        }
+        for v in chunks.into_remainder() {
+            *v = self.transform1(*v, w, divide);
+        }
+    }
+
+    #[inline]
+    fn transform4(&self, v: [Vector3<T>; 4], w: T, divide: bool) -> [Vector3<T>; 4] {
+        let h = |v: Vector3<T>| Vector4::new(v.x, v.y, v.z, w);
+        let m = backend::matrix4_mul(*self, Matrix4::from_cols(h(v[0]), h(v[1]), h(v[2]), h(v[3])));
+        let f = |c: Vector4<T>| if divide { c.wdiv() } else { Vector3::new(c.x, c.y, c.z) };
+        [f(m.col(0)), f(m.col(1)), f(m.col(2)), f(m.col(3))]
+    }
+
+    #[inline]
+    fn transform1(&self, v: Vector3<T>, w: T, divide: bool) -> Vector3<T> {
+        let c = backend::matrix4_mul_vector4(*self, Vector4::new(v.x, v.y, v.z, w));
+        if divide { c.wdiv() } else { Vector3::new(c.x, c.y, c.z) }
+    }
+}
+
+impl<T: Base + Neg<Output=T>> Matrix2<T> {
+    pub fn det(&self) -> T {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.det();
+        if self.is_singular(det) {
+            return None;
+        }
+        let d = T::one() / det;
+        Some(Matrix2::from_components_row_major(
+             d*self.m11, -d*self.m01,
+            -d*self.m10,  d*self.m00
+        ))
+    }
+
+    fn abs_permanent(&self) -> T {
+        let a = |r, c| abs(self.row_elem(r, c));
+        a(0, 0) * a(1, 1) + a(0, 1) * a(1, 0)
+    }
+}
+
 impl<T: Base + Neg<Output=T>> Matrix3<T> {
     pub fn det(&self) -> T {
         let m = |r,c| self.row_elem(r, c);
@@ -349,7 +741,7 @@ impl<T: Base + Neg<Output=T>> Matrix3<T> {
 
     pub fn inverse(&self) -> Option<Self> {
         let det = self.det();
-        if det == T::zero() { // TODO(henk): How to compare?
+        if self.is_singular(det) {
             None
         } else {
             let d = T::one() / det;
@@ -367,37 +759,15 @@ impl<T: Base + Neg<Output=T>> Matrix3<T> {
             ))
         }
     }
-}
 
-impl<T: BaseFloat + ApproxEq<Flt=T>> Matrix4<T> {
-    pub fn inverse(&self) -> Option<Self> {
-        let (m00, m01, m02, m03) = (self.row_elem(0, 0), self.row_elem(0, 1), self.row_elem(0, 2), self.row_elem(0, 3));
-        let (m10, m11, m12, m13) = (self.row_elem(1, 0), self.row_elem(1, 1), self.row_elem(1, 2), self.row_elem(1, 3));
-        let (m20, m21, m22, m23) = (self.row_elem(2, 0), self.row_elem(2, 1), self.row_elem(2, 2), self.row_elem(2, 3));
-        let (m30, m31, m32, m33) = (self.row_elem(3, 0), self.row_elem(3, 1), self.row_elem(3, 2), self.row_elem(3, 3));
-
-        let d = T::one() / self.det();
-
-        // TODO: Test for zero!
-
-        Some(Self::from_components_row_major(
-            d*(m11*m22*m33 + m12*m23*m31 + m13*m21*m32 - m11*m23*m32 - m12*m21*m33 - m13*m22*m31),
-            d*(m01*m23*m32 + m02*m21*m33 + m03*m22*m31 - m01*m22*m33 - m02*m23*m31 - m03*m21*m32),
-            d*(m01*m12*m33 + m02*m13*m31 + m03*m11*m32 - m01*m13*m32 - m02*m11*m33 - m03*m12*m31),
-            d*(m01*m13*m22 + m02*m11*m23 + m03*m12*m21 - m01*m12*m23 - m02*m13*m21 - m03*m11*m22),
-            d*(m10*m23*m32 + m12*m20*m33 + m13*m22*m30 - m10*m22*m33 - m12*m23*m30 - m13*m20*m32),
-            d*(m00*m22*m33 + m02*m23*m30 + m03*m20*m32 - m00*m23*m32 - m02*m20*m33 - m03*m22*m30),
-            d*(m00*m13*m32 + m02*m10*m33 + m03*m12*m30 - m00*m12*m33 - m02*m13*m30 - m03*m10*m32),
-            d*(m00*m12*m23 + m02*m13*m20 + m03*m10*m22 - m00*m13*m22 - m02*m10*m23 - m03*m12*m20),
-            d*(m10*m21*m33 + m11*m23*m30 + m13*m20*m31 - m10*m23*m31 - m11*m20*m33 - m13*m21*m30),
-            d*(m00*m23*m31 + m01*m20*m33 + m03*m21*m30 - m00*m21*m33 - m01*m23*m30 - m03*m20*m31),
-            d*(m00*m11*m33 + m01*m13*m30 + m03*m10*m31 - m00*m13*m31 - m01*m10*m33 - m03*m11*m30),
-            d*(m00*m13*m21 + m01*m10*m23 + m03*m11*m20 - m00*m11*m23 - m01*m13*m20 - m03*m10*m21),
-            d*(m10*m22*m31 + m11*m20*m32 + m12*m21*m30 - m10*m21*m32 - m11*m22*m30 - m12*m20*m31),
-            d*(m00*m21*m32 + m01*m22*m30 + m02*m20*m31 - m00*m22*m31 - m01*m20*m32 - m02*m21*m30),
-            d*(m00*m12*m31 + m01*m10*m32 + m02*m11*m30 - m00*m11*m32 - m01*m12*m30 - m02*m10*m31),
-            d*(m00*m11*m22 + m01*m12*m20 + m02*m10*m21 - m00*m12*m21 - m01*m10*m22 - m02*m11*m20)
-        ))
+    fn abs_permanent(&self) -> T {
+        let a = |r, c| abs(self.row_elem(r, c));
+          a(0, 0) * a(1, 1) * a(2, 2)
+        + a(0, 1) * a(1, 2) * a(2, 0)
+        + a(0, 2) * a(1, 0) * a(2, 1)
+        + a(2, 0) * a(1, 1) * a(0, 2)
+        + a(2, 1) * a(1, 2) * a(0, 0)
+        + a(2, 2) * a(1, 0) * a(0, 1)
     }
 }
 
@@ -418,9 +788,10 @@ impl<T: Base + Neg<Output=T>> Matrix4<T> {
         m03*m10*m21*m32 - m03*m11*m22*m30 - m03*m12*m20*m31
     }
 
+    // The same as inverse but without going through the backend.
     pub fn inverse1(&self) -> Option<Self> {
         let det = self.det();
-        if det == T::zero() { // TODO(henk): How to compare
+        if self.is_singular(det) {
             None
         } else {
             let d = T::one() / det;
@@ -445,18 +816,114 @@ impl<T: Base + Neg<Output=T>> Matrix4<T> {
             ))
         }
     }
+
+    fn abs_permanent(&self) -> T {
+        let a = |r, c| abs(self.row_elem(r, c));
+        // Expansion along the first row, p3 is the permanent of a minor
+        let p3 = |c0, c1, c2|
+              a(1, c0) * (a(2, c1) * a(3, c2) + a(2, c2) * a(3, c1))
+            + a(1, c1) * (a(2, c0) * a(3, c2) + a(2, c2) * a(3, c0))
+            + a(1, c2) * (a(2, c0) * a(3, c1) + a(2, c1) * a(3, c0));
+        a(0, 0) * p3(1, 2, 3) + a(0, 1) * p3(0, 2, 3) + a(0, 2) * p3(0, 1, 3) + a(0, 3) * p3(0, 1, 2)
+    }
 }
 
-impl<T: BaseFloat> Matrix3<T> {
-    pub fn new_normal_matrix(model_matrix: &Matrix3<T>) -> Option<Self> {
-        if let Some(inv) = model_matrix.inverse() {
-            Some(Matrix3::from(inv.transpose()))
-        } else {
+impl<T: BaseFloat + ApproxEq<Flt=T>> Matrix4<T> {
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_singular(self.det()) {
             None
+        } else {
+            backend::matrix4_inverse(*self)
+        }
+    }
+}
+
+// Returned when inverting a matrix that is singular or too ill-conditioned for the inverse to be
+// meaningful.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InverseError<T> {
+    pub det: T,
+    // Estimate of the condition number in the infinity norm. Infinite if the determinant is zero.
+    pub condition: T,
+}
+
+impl<T: fmt::Display> fmt::Display for InverseError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matrix is singular (det = {}, condition = {})", self.det, self.condition)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> error::Error for InverseError<T> {}
+
+fn abs<T: Base + Neg<Output=T>>(x: T) -> T {
+    if x < T::zero() { -x } else { x }
+}
+
+fn is_integer<T: Base>() -> bool {
+    T::one() / (T::one() + T::one()) == T::zero()
+}
+
+// The permanent of |m| is the sum of the absolute values of the terms of the determinant. If the
+// determinant vanishes next to it (times the dimension to cover the rounding of the terms), it is
+// rounding noise. Scaling a row or column scales both the same way, so unlike an absolute
+// threshold this accepts large translations and tiny scales. Integers have no rounding noise, and
+// the permanent overflows long before the determinant does, so for them it is just det == 0.
+macro_rules! implement_singular_check {
+    ($type:ident { dim: $dim:expr }) => {
+        impl<T: Base + Neg<Output=T>> $type<T> {
+            fn is_singular(&self, det: T) -> bool {
+                if is_integer::<T>() {
+                    return det == T::zero();
+                }
+                let permanent = self.abs_permanent();
+                let tolerance = (0..$dim).fold(T::zero(), |sum, _| sum + permanent);
+                tolerance + abs(det) == tolerance
+            }
+        }
+    }
+}
+
+implement_singular_check!(Matrix2 { dim: 2 });
+implement_singular_check!(Matrix3 { dim: 3 });
+implement_singular_check!(Matrix4 { dim: 4 });
+
+// The condition number is estimated as |m| * |m^-1| in the infinity norm (maximum absolute row
+// sum). A matrix counts as singular if the reciprocal of that estimate is below the tolerance.
+// inverse() does not check the condition, as it grows with translations and scales: a translation
+// by t alone has a condition of about t^2, so with a tolerance of 1e-6 a translation by 3000 is
+// already rejected. Use inverse() for transforms and this for solving systems.
+macro_rules! implement_try_inverse {
+    ($type:ident { dim: $dim:expr } where T: $($bounds:tt)+) => {
+        impl<T: $($bounds)+> $type<T> {
+            pub fn try_inverse_with_tolerance(&self, eps: T) -> Result<Self, InverseError<T>> {
+                let det = self.det();
+                let inverse = self.inverse().ok_or(InverseError { det, condition: T::infinity() })?;
+                let condition = self.norm_inf() * inverse.norm_inf();
+                // A NaN condition is caused by non-finite elements
+                if condition.is_nan() || condition * eps >= T::one() {
+                    return Err(InverseError { det, condition });
+                }
+                Ok(inverse)
+            }
+
+            fn norm_inf(&self) -> T {
+                (0..$dim).map(|r| (0..$dim).fold(T::zero(), |sum, c| sum + self.row_elem(r, c).abs()))
+                    .fold(T::zero(), T::max)
+            }
         }
     }
 }
 
+implement_try_inverse!(Matrix2 { dim: 2 } where T: BaseFloat);
+implement_try_inverse!(Matrix3 { dim: 3 } where T: BaseFloat);
+implement_try_inverse!(Matrix4 { dim: 4 } where T: BaseFloat + ApproxEq<Flt=T>);
+
+impl<T: BaseFloat> Matrix3<T> {
+    pub fn new_normal_matrix(model_matrix: &Matrix3<T>) -> Option<Self> {
+        model_matrix.inverse().map(|inv| inv.transpose())
+    }
+}
+
 impl<T: BaseFloat> From<Matrix4<T>> for Matrix3<T> {
     fn from(m: Matrix4<T>) -> Self {
         Matrix3::from_components_row_major(
@@ -467,6 +934,28 @@ impl<T: BaseFloat> From<Matrix4<T>> for Matrix3<T> {
     }
 }
 
+// Drops the last row, which is (0, 0, 0, 1) for affine transformations.
+impl<T: Base> From<Matrix4<T>> for Matrix3x4<T> {
+    fn from(m: Matrix4<T>) -> Self {
+        Matrix3x4::from_components_row_major(
+            m.m00, m.m01, m.m02, m.m03,
+            m.m10, m.m11, m.m12, m.m13,
+            m.m20, m.m21, m.m22, m.m23
+        )
+    }
+}
+
+impl<T: Base> From<Matrix3x4<T>> for Matrix4<T> {
+    fn from(m: Matrix3x4<T>) -> Self {
+        Matrix4::from_components_row_major(
+            m.m00    , m.m01    , m.m02    , m.m03,
+            m.m10    , m.m11    , m.m12    , m.m13,
+            m.m20    , m.m21    , m.m22    , m.m23,
+            T::zero(), T::zero(), T::zero(), T::one()
+        )
+    }
+}
+
 impl<T: BaseFloat> Matrix4<T> {
     pub fn new_translation(x: T, y: T, z: T) -> Self {
         Matrix4::from_components_row_major(
@@ -510,6 +999,10 @@ impl<T: BaseFloat> Matrix4<T> {
         )
     }
 
+    pub fn new_rotation_from_angle<A: Into<Rad<T>>>(axis: Vector3<T>, angle: A) -> Self {
+        Self::new_rotation(axis, angle.into().0)
+    }
+
     pub fn new_rotation_x(angle: T) -> Self {
         Matrix4::from_components_row_major(
             T::one() , T::zero()    ,  T::zero()    , T::zero(),
@@ -517,7 +1010,11 @@ impl<T: BaseFloat> Matrix4<T> {
             T::zero(), T::sin(angle),  T::cos(angle), T::zero(),
             T::zero(), T::zero()    ,  T::zero()    , T::one()
         )
-    }    
+    }
+
+    pub fn new_rotation_x_from_angle<A: Into<Rad<T>>>(angle: A) -> Self {
+        Self::new_rotation_x(angle.into().0)
+    }
 
     pub fn new_rotation_y(angle: T) -> Self {
         Matrix4::from_components_row_major(
@@ -528,6 +1025,10 @@ impl<T: BaseFloat> Matrix4<T> {
         )
     }
 
+    pub fn new_rotation_y_from_angle<A: Into<Rad<T>>>(angle: A) -> Self {
+        Self::new_rotation_y(angle.into().0)
+    }
+
     pub fn new_rotation_z(angle: T) -> Self {
         Matrix4::from_components_row_major(
             T::cos(angle), -T::sin(angle), T::zero(), T::zero(),
@@ -537,6 +1038,10 @@ impl<T: BaseFloat> Matrix4<T> {
         )
     }
 
+    pub fn new_rotation_z_from_angle<A: Into<Rad<T>>>(angle: A) -> Self {
+        Self::new_rotation_z(angle.into().0)
+    }
+
     pub fn new_perspective_from_bounds(left: T, right: T, bottom: T, top: T, z_near: T, z_far: T) -> Self {
         let two = T::one() + T::one();
         let w = right - left;
@@ -550,6 +1055,8 @@ impl<T: BaseFloat> Matrix4<T> {
         )
     }
 
+    // fov is the horizontal field of view in degrees. Prefer new_perspective_from_fov_angle which
+    // makes the unit explicit.
     pub fn new_perspective_from_fov(fov: T, aspect: T, z_near: T, z_far: T) -> Self {
         let pi = T::from(::std::f32::consts::PI).unwrap();
         let circ = T::from(360.0).unwrap();
@@ -560,6 +1067,15 @@ impl<T: BaseFloat> Matrix4<T> {
         Self::new_perspective_from_bounds(left, right, bottom, top, z_near, z_far)
     }
 
+    pub fn new_perspective_from_fov_angle<A: Into<Rad<T>>>(fov: A, aspect: T, z_near: T, z_far: T) -> Self {
+        let two = T::one() + T::one();
+        let right = z_near * (fov.into() / two).tan();
+        let left = -right;
+        let top = right / aspect;
+        let bottom = -top;
+        Self::new_perspective_from_bounds(left, right, bottom, top, z_near, z_far)
+    }
+
     pub fn new_orthographic_from_bounds(left: T, right: T, bottom: T, top: T, z_near: T, z_far: T) -> Self {
         let two = T::one() + T::one();
         let w = right - left;
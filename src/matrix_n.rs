@@ -0,0 +1,311 @@
+use std::{
+    array,
+    ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg},
+};
+
+use crate::{
+    traits::*,
+    vector_n::*,
+    matrix::*,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// A matrix with R rows and C columns of arbitrary size. Like the fixed size matrices it is stored
+// column-major, so the memory layout of e.g. MatrixMN<T, 4, 4> matches the one of Matrix4<T>.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatrixMN<T: Base, const R: usize, const C: usize> {
+    pub cols: [VectorN<T, R>; C],
+}
+
+impl<T: Base, const R: usize, const C: usize> MatrixMN<T, R, C> {
+    pub fn from_cols(cols: [VectorN<T, R>; C]) -> Self {
+        MatrixMN { cols }
+    }
+
+    pub fn from_rows(rows: [VectorN<T, C>; R]) -> Self {
+        MatrixMN::from_fn(|r, c| rows[r].elems[c])
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        let mut res = MatrixMN::zero();
+        for c in 0..C {
+            for r in 0..R {
+                res.cols[c].elems[r] = f(r, c);
+            }
+        }
+        res
+    }
+
+    pub fn transpose(&self) -> MatrixMN<T, C, R> {
+        MatrixMN::from_cols(self.to_rows())
+    }
+
+    pub fn col(&self, index: usize) -> VectorN<T, R> {
+        self.cols[index]
+    }
+
+    pub fn row(&self, index: usize) -> VectorN<T, C> {
+        VectorN::from_fn(|c| self.cols[c].elems[index])
+    }
+
+    fn to_rows(self) -> [VectorN<T, C>; R] {
+        let mut rows = [VectorN::zero(); R];
+        for (r, row) in rows.iter_mut().enumerate() {
+            *row = self.row(r);
+        }
+        rows
+    }
+
+    pub fn set_col_elem(&mut self, col_index: usize, elem_index: usize, value: T) {
+        self.cols[col_index].elems[elem_index] = value;
+    }
+
+    pub fn zero() -> Self {
+        MatrixMN::from_cols([VectorN::zero(); C])
+    }
+
+    pub fn one() -> Self {
+        MatrixMN::from_cols([VectorN::one(); C])
+    }
+}
+
+impl<T: Base, const N: usize> MatrixMN<T, N, N> {
+    #[inline]
+    pub fn dims() -> usize {
+        N
+    }
+
+    pub fn from_diagonal(d: VectorN<T, N>) -> Self {
+        MatrixMN::from_fn(|r, c| if r == c { d.elems[r] } else { T::zero() })
+    }
+
+    pub fn identity() -> Self {
+        MatrixMN::from_diagonal(VectorN::one())
+    }
+}
+
+impl<T: Base, const R: usize, const C: usize> Default for MatrixMN<T, R, C> {
+    fn default() -> Self {
+        MatrixMN::zero()
+    }
+}
+
+// --------------------------------------------------------------------------
+// Element access
+// --------------------------------------------------------------------------
+
+impl<T: Base, const R: usize, const C: usize> MatrixAccess for MatrixMN<T, R, C> {
+    type Output = T;
+    #[inline]
+    fn col_elem(self, col_index: usize, elem_index: usize) -> Self::Output {
+        self.cols[col_index].elems[elem_index]
+    }
+    #[inline]
+    fn row_elem(self, row_index: usize, elem_index: usize) -> Self::Output {
+        self.cols[elem_index].elems[row_index]
+    }
+}
+
+impl<'a, T: Base, const R: usize, const C: usize> MatrixAccessRef<'a> for &'a MatrixMN<T, R, C> {
+    type Output = T;
+    #[inline]
+    fn col_elem_ref(self, col_index: usize, elem_index: usize) -> &'a Self::Output {
+        &self.cols[col_index].elems[elem_index]
+    }
+    #[inline]
+    fn row_elem_ref(self, row_index: usize, elem_index: usize) -> &'a Self::Output {
+        &self.cols[elem_index].elems[row_index]
+    }
+}
+
+impl<'a, T: Base, const R: usize, const C: usize> MatrixAccessMut<'a> for &'a mut MatrixMN<T, R, C> {
+    type Output = T;
+    #[inline]
+    fn col_elem_mut(self, col_index: usize, elem_index: usize) -> &'a mut Self::Output {
+        &mut self.cols[col_index].elems[elem_index]
+    }
+    #[inline]
+    fn row_elem_mut(self, row_index: usize, elem_index: usize) -> &'a mut Self::Output {
+        &mut self.cols[elem_index].elems[row_index]
+    }
+}
+
+impl<T: BaseFloat, const R: usize, const C: usize> ApproxEq for MatrixMN<T, R, C>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.cols.iter().zip(other.cols.iter()).all(|(a, b)| a.approx_eq(b, epsilon, ulps))
+    }
+}
+
+// --------------------------------------------------------------------------
+// Operators
+// --------------------------------------------------------------------------
+
+// m + m
+implement_binary_operator!([const R: usize, const C: usize] Add<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn add(lhs, rhs) -> MatrixMN<T, R, C> {{
+        let mut res = lhs;
+        res += rhs;
+        res
+    }}
+);
+
+// m += m
+implement_binary_assign_operator!([const R: usize, const C: usize] AddAssign<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn add_assign(lhs, rhs) {{
+        for (a, b) in lhs.cols.iter_mut().zip(rhs.cols.iter()) { *a += b; }
+    }}
+);
+
+// m - m
+implement_binary_operator!([const R: usize, const C: usize] Sub<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn sub(lhs, rhs) -> MatrixMN<T, R, C> {{
+        let mut res = lhs;
+        res -= rhs;
+        res
+    }}
+);
+
+// m -= m
+implement_binary_assign_operator!([const R: usize, const C: usize] SubAssign<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn sub_assign(lhs, rhs) {{
+        for (a, b) in lhs.cols.iter_mut().zip(rhs.cols.iter()) { *a -= b; }
+    }}
+);
+
+// m * s
+implement_binary_operator!([const R: usize, const C: usize] Mul<T> for MatrixMN<T, R, C>,
+    fn mul(matrix, scalar) -> MatrixMN<T, R, C> {{
+        let mut res = matrix;
+        res *= scalar;
+        res
+    }}
+);
+
+// m *= s
+implement_binary_assign_operator!([const R: usize, const C: usize] MulAssign<T> for MatrixMN<T, R, C>,
+    fn mul_assign(matrix, scalar) {{
+        for col in matrix.cols.iter_mut() { *col *= scalar; }
+    }}
+);
+
+// m / s
+implement_binary_operator!([const R: usize, const C: usize] Div<T> for MatrixMN<T, R, C>,
+    fn div(matrix, scalar) -> MatrixMN<T, R, C> {{
+        let mut res = matrix;
+        res /= scalar;
+        res
+    }}
+);
+
+// m /= s
+implement_binary_assign_operator!([const R: usize, const C: usize] DivAssign<T> for MatrixMN<T, R, C>,
+    fn div_assign(matrix, scalar) {{
+        for col in matrix.cols.iter_mut() { *col /= scalar; }
+    }}
+);
+
+// m * v
+implement_binary_operator!([const R: usize, const C: usize] Mul<VectorN<T, C>> for MatrixMN<T, R, C>,
+    fn mul(lhs, rhs) -> VectorN<T, R> {{
+        let mut res = VectorN::zero();
+        for c in 0..C {
+            res += lhs.cols[c] * rhs.elems[c];
+        }
+        res
+    }}
+);
+
+// m * m
+implement_binary_operator!([const R: usize, const K: usize, const C: usize] Mul<MatrixMN<T, K, C>> for MatrixMN<T, R, K>,
+    fn mul(lhs, rhs) -> MatrixMN<T, R, C> {{
+        let mut res = MatrixMN::zero();
+        for c in 0..C {
+            res.cols[c] = lhs * rhs.cols[c];
+        }
+        res
+    }}
+);
+
+// m cw_min m
+implement_binary_operator!([const R: usize, const C: usize] CwMin<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn cw_min(lhs, rhs) -> MatrixMN<T, R, C> {
+        MatrixMN::from_cols(array::from_fn(|c| lhs.cols[c].cw_min(rhs.cols[c])))
+    }
+);
+
+// m cw_max m
+implement_binary_operator!([const R: usize, const C: usize] CwMax<MatrixMN<T, R, C>> for MatrixMN<T, R, C>,
+    fn cw_max(lhs, rhs) -> MatrixMN<T, R, C> {
+        MatrixMN::from_cols(array::from_fn(|c| lhs.cols[c].cw_max(rhs.cols[c])))
+    }
+);
+
+implement_unary_operator!([const R: usize, const C: usize] Neg for MatrixMN<T, R, C> where T: Neg<Output=T>,
+    fn neg(this) -> MatrixMN<T, R, C> {
+        MatrixMN::from_cols(array::from_fn(|c| -this.cols[c]))
+    }
+);
+
+// --------------------------------------------------------------------------
+// Serde
+// --------------------------------------------------------------------------
+
+impl<T: Base + Serialize, const R: usize, const C: usize> Serialize for MatrixMN<T, R, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self.cols, serializer)
+    }
+}
+
+impl<'de, T: Base + Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de> for MatrixMN<T, R, C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer, VectorN::zero()).map(MatrixMN::from_cols)
+    }
+}
+
+// --------------------------------------------------------------------------
+// Conversions
+// --------------------------------------------------------------------------
+
+macro_rules! implement_matrix_n_conversion {
+    ($matrix_type:ident { shape: $rows:literal x $cols:literal }) => {
+        impl<T: Base> From<$matrix_type<T>> for MatrixMN<T, $rows, $cols> {
+            fn from(m: $matrix_type<T>) -> Self {
+                let mut res = MatrixMN::zero();
+                for c in 0..$cols {
+                    res.cols[c] = m.col(c).into();
+                }
+                res
+            }
+        }
+
+        impl<T: Base> From<MatrixMN<T, $rows, $cols>> for $matrix_type<T> {
+            fn from(m: MatrixMN<T, $rows, $cols>) -> Self {
+                let mut res = $matrix_type::zero();
+                for c in 0..$cols {
+                    for r in 0..$rows {
+                        res.set_col_elem(c, r, m.cols[c].elems[r]);
+                    }
+                }
+                res
+            }
+        }
+    }
+}
+
+implement_matrix_n_conversion!(Matrix2 { shape: 2 x 2 });
+implement_matrix_n_conversion!(Matrix3 { shape: 3 x 3 });
+implement_matrix_n_conversion!(Matrix4 { shape: 4 x 4 });
+implement_matrix_n_conversion!(Matrix2x3 { shape: 2 x 3 });
+implement_matrix_n_conversion!(Matrix2x4 { shape: 2 x 4 });
+implement_matrix_n_conversion!(Matrix3x2 { shape: 3 x 2 });
+implement_matrix_n_conversion!(Matrix3x4 { shape: 3 x 4 });
+implement_matrix_n_conversion!(Matrix4x2 { shape: 4 x 2 });
+implement_matrix_n_conversion!(Matrix4x3 { shape: 4 x 3 });
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::matrix_n::*;
+    use crate::vector_n::*;
+    use crate::matrix::*;
+    use crate::traits::*;
+
+    type Mat4 = Matrix4<f32>;
+
+    #[test]
+    fn matrix_n_from_rows() {
+        let m = MatrixMN::<f32, 2, 3>::from_rows([
+            VectorN::new([1.0, 2.0, 3.0]),
+            VectorN::new([4.0, 5.0, 6.0]),
+        ]);
+        assert_eq!(m.col(1), VectorN::new([2.0, 5.0]));
+        assert_eq!(m.row(1), VectorN::new([4.0, 5.0, 6.0]));
+        assert_eq!(m.row_elem(0, 2), 3.0);
+        assert_eq!(m.col_elem(0, 1), 4.0);
+        assert_eq!(m.transpose().row(2), VectorN::new([3.0, 6.0]));
+    }
+
+    #[test]
+    fn matrix_n_identity() {
+        let i = MatrixMN::<f32, 5, 5>::identity();
+        let v = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(i * v, v);
+        assert_eq!(i * i, i);
+        assert_eq!(MatrixMN::<f32, 5, 5>::dims(), 5);
+    }
+
+    #[test]
+    fn matrix_n_mul() {
+        let a = MatrixMN::<i32, 2, 3>::from_fn(|r, c| (r * 3 + c) as i32);
+        let b = a.transpose();
+        let c: MatrixMN<i32, 2, 2> = a * b;
+        assert_eq!(c, MatrixMN::from_rows([VectorN::new([5, 14]), VectorN::new([14, 50])]));
+        assert_eq!(a * VectorN::new([1, 1, 1]), VectorN::new([3, 12]));
+    }
+
+    #[test]
+    fn matrix_n_arithmetic() {
+        let a = MatrixMN::<f32, 3, 2>::one();
+        let mut b = a + a;
+        assert_eq!(b, a * 2.0);
+        b -= a;
+        assert_eq!(b, a);
+        assert_eq!(-a - a, a * -2.0);
+        assert_eq!((a * 4.0) / 2.0, a + a);
+        assert_eq!(a.cw_min(MatrixMN::zero()), MatrixMN::zero());
+        assert_eq!(a.cw_max(MatrixMN::zero()), a);
+    }
+
+    #[test]
+    fn matrix_n_matches_matrix4() {
+        let a = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation_x(0.5);
+        let b = Mat4::new_scale(2.0, 3.0, 4.0) * Mat4::new_rotation_z(-0.3);
+        let n = MatrixMN::from(a) * MatrixMN::from(b);
+        assert!(Mat4::from(n).approx_eq(&(a * b), f32::EPSILON, 4));
+        assert_eq!(Mat4::from(MatrixMN::from(a)), a);
+        assert_eq!(MatrixMN::from(a).transpose(), MatrixMN::from(a.transpose()));
+    }
+
+    #[test]
+    fn matrix_n_rectangular_conversion() {
+        let m = Matrix2x3::from_components_row_major(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0
+        );
+        let n = MatrixMN::<f32, 2, 3>::from(m);
+        assert_eq!(n.row(0), VectorN::new([1.0, 2.0, 3.0]));
+        assert_eq!(Matrix2x3::from(n), m);
+    }
+}
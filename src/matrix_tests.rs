@@ -1,11 +1,17 @@
 #[cfg(test)]
 mod tests {
-    use matrix::*;
-    use vector::*;
-    use traits::*;
+    use crate::matrix::*;
+    use crate::matrix_n::*;
+    use crate::vector::*;
+    use crate::traits::*;
+    use crate::angle::*;
+    use crate::point::*;
 
     use std::f32;    
 
+    type Pnt2 = Point2<f32>;
+    type Pnt3 = Point3<f32>;
+
     type Mat2 = Matrix2<f32>;
     type Mat3 = Matrix3<f32>;
     type Mat4 = Matrix4<f32>;
@@ -14,6 +20,8 @@ mod tests {
     type Vec3 = Vector3<f32>;
     type Vec4 = Vector4<f32>;
 
+    const EPS: f32 = 4.0*f32::EPSILON;
+
     // --------------------------------------------------------------------------
     //
     // Matrix2
@@ -110,11 +118,27 @@ mod tests {
 
     #[test]
     fn matrix2_mulmv_ref_mutref() {
-        let ref m = Mat2::from_components_row_major(1.0, 2.0, 3.0, 4.0);
-        let ref mut v = Vec2::new(5.0, 6.0);
+        let m = &Mat2::from_components_row_major(1.0, 2.0, 3.0, 4.0);
+        let v = &mut Vec2::new(5.0, 6.0);
         assert_eq!(m*v, Vec2::new(17.0, 39.0));
     }
 
+    // --------------------------------------------------------------------------
+    // det / inverse
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn matrix2_det() {
+        assert_eq!(Mat2::from_components_row_major(1.0, 2.0, 3.0, 4.0).det(), -2.0);
+    }
+
+    #[test]
+    fn matrix2_inverse() {
+        let m = Mat2::from_components_row_major(1.0, 2.0, 3.0, 4.0);
+        assert!((m * m.inverse().unwrap()).approx_eq(&Mat2::identity(), f32::EPSILON, 2));
+        assert!(Mat2::from_components_row_major(1.0, 2.0, 2.0, 4.0).inverse().is_none());
+    }
+
     // --------------------------------------------------------------------------
     //
     // Matrix3
@@ -194,31 +218,31 @@ mod tests {
 
     #[test]
     fn matrix3_col_elem_ref_to_val() {
-        let ref m = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let m = &Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         assert_eq!(m.col_elem(1, 1), 5.0);
     }
 
     #[test]
     fn matrix3_col_elem_ref_to_ref() {
-        let ref m = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let m = &Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         assert_eq!(m.col_elem_ref(1, 1), &5.0);
     }
 
     #[test]
     fn matrix3_col_elem_mutref_to_val() {
-        let ref mut m = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let m = &mut Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         assert_eq!(m.col_elem(1, 1), 5.0);
     }
 
     #[test]
     fn matrix3_col_elem_mutref_to_ref() {
-        let ref mut m = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let m = &mut Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         assert_eq!(m.col_elem_ref(1, 1), &5.0);
     }
 
     #[test]
     fn matrix3_col_elem_mutref_to_mutref() {
-        let ref mut m = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let m = &mut Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         assert_eq!(m.col_elem_mut(1, 1), &mut 5.0);
     }
 
@@ -255,7 +279,7 @@ mod tests {
     #[test]
     fn matrix3_approx_eq1() {
         let     m1 = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-        let mut m2 = m1.clone();
+        let mut m2 = m1;
         m2.set_col_elem(1, 0, 4.0 + f32::EPSILON);
         assert!(m1.approx_eq(&m2, f32::EPSILON, 0));
     }
@@ -263,7 +287,7 @@ mod tests {
     #[test]
     fn matrix3_approx_eq2() {
         let     m1 = Mat3::from_components_col_major(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-        let mut m2 = m1.clone();
+        let mut m2 = m1;
         m2.set_col_elem(1, 0, 4.0 + 3.0*f32::EPSILON);
         assert!(!m1.approx_eq(&m2, f32::EPSILON, 0));
     }
@@ -395,8 +419,8 @@ mod tests {
             3.0, -3.0,  1.0,
             1.0,  4.0, -6.0
         );
-        assert!(m1.approx_eq(&m2, ::std::f32::EPSILON, 2));
-        assert!(!m1.approx_eq(&m3, ::std::f32::EPSILON, 2));
+        assert!(m1.approx_eq(&m2, f32::EPSILON, 2));
+        assert!(!m1.approx_eq(&m3, f32::EPSILON, 2));
     }
 
     // --------------------------------------------------------------------------
@@ -422,7 +446,7 @@ mod tests {
 
     #[test]
     fn matrix4_inverse0() {
-        assert!(Mat4::identity().inverse().unwrap().approx_eq(&Mat4::identity(), ::std::f32::EPSILON, 2));
+        assert!(Mat4::identity().inverse().unwrap().approx_eq(&Mat4::identity(), f32::EPSILON, 2));
     }
 
     #[test]
@@ -469,7 +493,7 @@ mod tests {
             0.0, 8.0/4.0, 0.0, 1.0
         );
         println!("m ?= result ... {:?} ?= {:?}", m.inverse().unwrap(), result);
-        assert!(m.inverse().unwrap().approx_eq(&result, ::std::f32::EPSILON, 2));
+        assert!(m.inverse().unwrap().approx_eq(&result, f32::EPSILON, 2));
     }
 
     #[test]
@@ -481,6 +505,325 @@ mod tests {
         let u = (p*v*x).wdiv();
         let r = (p*v).inverse().unwrap()*Vec4::new(u.x, u.y, u.z, 1.0);
         println!("{:?} -> {:?}", x, r);
-        assert!(x.approx_eq(&r, 2.0*::std::f32::EPSILON, 3));
+        assert!(x.approx_eq(&r, 2.0*f32::EPSILON, 3));
+    }
+
+    #[test]
+    fn matrix4_inverse_singular() {
+        assert!(Mat4::zero().inverse().is_none());
+        assert!(Mat4::zero().inverse1().is_none());
+        let m = Mat4::from_components_row_major(
+            1.0, 2.0, 3.0, 4.0,
+            2.0, 4.0, 6.0, 8.0,
+            0.0, 1.0, 0.0, 1.0,
+            1.0, 0.0, 0.0, 1.0
+        );
+        assert!(m.inverse().is_none());
+        assert!(Mat4::new_scale(1.0, 1.0, 0.0).inverse().is_none());
+    }
+
+    #[test]
+    fn matrix_inverse_near_singular() {
+        // Rows that are linearly dependent up to rounding
+        let m = Mat3::from_components_row_major(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0 + 1e-6
+        );
+        assert!(m.inverse().is_none());
+        let m = Mat2::from_components_row_major(1.0, 1.0, 1.0, 1.0 + 1e-7);
+        assert!(m.inverse().is_none());
+        let m = Mat4::from_components_row_major(
+            1.0, 2.0, 3.0, 0.0,
+            4.0, 5.0, 6.0, 0.0,
+            7.0, 8.0, 9.0 + 1e-6, 0.0,
+            0.0, 0.0, 0.0, 1.0
+        );
+        assert!(m.inverse().is_none());
+        assert!(m.inverse1().is_none());
+        // Tiny but well-conditioned matrices are fine
+        let m = Mat3::from_diagonal(Vec3::new(1e-9, 1e-9, 1e-9));
+        assert!((m * m.inverse().unwrap()).approx_eq(&Mat3::identity(), 4.0 * f32::EPSILON, 4));
+        // So are ill-conditioned ones that are exactly invertible, only the tolerance rejects them
+        let m = Mat4::new_scale(1.0, 1.0, 1e-9);
+        assert!(m.inverse().unwrap().approx_eq(&Mat4::new_scale(1.0, 1.0, 1e9), f32::EPSILON, 2));
+        assert!(m.inverse1().unwrap().approx_eq(&Mat4::new_scale(1.0, 1.0, 1e9), f32::EPSILON, 2));
+        assert!(m.try_inverse_with_tolerance(f32::EPSILON).is_err());
+    }
+
+    #[test]
+    fn matrix4_inverse_large_translation() {
+        for &t in &[3000.0, 10000.0, 1e6] {
+            let m = Mat4::new_translation(t, -t, 0.5 * t);
+            let expected = Mat4::new_translation(-t, t, -0.5 * t);
+            assert!(m.inverse().unwrap().approx_eq(&expected, f32::EPSILON, 2), "{}", t);
+            assert!(m.inverse1().is_some(), "{}", t);
+        }
+        assert!(Mat4::new_translation(3000.0, 0.0, 0.0).try_inverse_with_tolerance(f32::EPSILON).is_err());
+    }
+
+    #[test]
+    fn matrix4_try_inverse_rejects_large_translation() {
+        // The condition of a translation grows with its square, so even a realistic tolerance
+        // rejects it while inverse() succeeds
+        let m = Mat4::new_translation(3000.0, 0.0, 0.0);
+        let err = m.try_inverse_with_tolerance(1e-6).unwrap_err();
+        assert!(err.condition.approx_eq(&(3001.0 * 3001.0), 1.0, 4), "{:?}", err);
+        assert!(m.inverse().is_some());
+        assert!(Mat4::new_translation(300.0, 0.0, 0.0).try_inverse_with_tolerance(1e-6).is_ok());
+    }
+
+    #[test]
+    fn matrix4_inverse_distant_view_projection() {
+        let p = Mat4::new_perspective_from_bounds(-1.0, 1.0, -1.0, 1.0, 1.0, 100.0);
+        for &d in &[1e3, 1e4, 1e5] {
+            let eye = Vec3::new(d, 0.5 * d, -d);
+            let target = Vec3::new(d, 0.5 * d, -d - 10.0);
+            let vp = p * Mat4::new_look_at(eye, target, Vec3::new(0.0, 1.0, 0.0));
+            let world = Vec4::new(d + 1.0, 0.5 * d - 2.0, -d - 20.0, 1.0);
+            let inv = vp.inverse().unwrap();
+            assert!(vp.inverse1().is_some());
+            let clip = vp * world;
+            let back = inv * clip;
+            let back = back / back.w;
+            assert!((back - world).length() <= d * 1e-5, "{:?} != {:?}", back, world);
+        }
+    }
+
+    #[test]
+    fn matrix_inverse_integer() {
+        let m = Matrix2::<i32>::from_components_row_major(2, 1, 1, 1);
+        assert_eq!(m.inverse(), Some(Matrix2::from_components_row_major(1, -1, -1, 2)));
+        assert_eq!(Matrix2::<i32>::from_components_row_major(2, 4, 1, 2).inverse(), None);
+        let m = Matrix3::<i32>::from_components_row_major(1, 0, 5, 0, 1, 0, 0, 0, 1);
+        assert_eq!(m.inverse(), Some(Matrix3::from_components_row_major(1, 0, -5, 0, 1, 0, 0, 0, 1)));
+        let m = Matrix4::<i64>::from_components_row_major(2, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1);
+        assert_eq!(m.inverse1(), Some(Matrix4::from_components_row_major(1, -1, 0, 0, -1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)));
+        assert_eq!(Matrix4::<i64>::zero().inverse1(), None);
+    }
+
+    #[test]
+    fn matrix_inverse_integer_large_values() {
+        // The permanents overflow i32 while the determinants are 1
+        let m = Matrix2::<i32>::from_components_row_major(40000, 39999, 40001, 40000);
+        assert_eq!(m.inverse(), Some(Matrix2::from_components_row_major(40000, -39999, -40001, 40000)));
+        let m = Matrix3::<i32>::from_components_row_major(30000, 29999, 0, 30001, 30000, 0, 0, 0, 1);
+        assert_eq!(m.inverse(), Some(Matrix3::from_components_row_major(30000, -29999, 0, -30001, 30000, 0, 0, 0, 1)));
+        let m = Matrix4::<i32>::from_components_row_major(1000, 999, 0, 0, 1001, 1000, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1);
+        assert_eq!(m.det(), 1);
+        assert!(m.inverse1().is_some());
+        let m = Matrix2::<i32>::from_components_row_major(40000, 40000, 40000, 40000);
+        assert_eq!(m.inverse(), None);
+    }
+
+    #[test]
+    fn matrix_try_inverse_with_tolerance() {
+        let m = Mat3::from_diagonal(Vec3::new(1.0, 2.0, 1e-3));
+        let inv = m.try_inverse_with_tolerance(1e-4).unwrap();
+        assert!(inv.approx_eq(&Mat3::from_diagonal(Vec3::new(1.0, 0.5, 1e3)), 1e-4, 4));
+        let err = m.try_inverse_with_tolerance(1e-2).unwrap_err();
+        assert!(err.det.approx_eq(&2e-3, f32::EPSILON, 2));
+        assert!(err.condition.approx_eq(&2e3, 1e-3, 4));
+        assert_eq!(format!("{}", Mat2::zero().try_inverse_with_tolerance(1e-6).unwrap_err()), "matrix is singular (det = 0, condition = inf)");
+
+        let m = Mat4::new_scale(4.0, 1.0, 1.0);
+        assert!(m.try_inverse_with_tolerance(0.3).is_err());
+        assert!(m.try_inverse_with_tolerance(0.2).is_ok());
+    }
+
+    // --------------------------------------------------------------------------
+    // Angle typed constructors
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn matrix4_new_rotation_from_angle() {
+        let axis = Vec3::new(0.0, 0.6, 0.8);
+        assert!(Mat4::new_rotation_from_angle(axis, Deg(90.0)).approx_eq(&Mat4::new_rotation(axis, f32::consts::FRAC_PI_2), f32::EPSILON, 2));
+        assert!(Mat4::new_rotation_from_angle(axis, Rad(0.5)).approx_eq(&Mat4::new_rotation(axis, 0.5), f32::EPSILON, 2));
+    }
+
+    #[test]
+    fn matrix4_new_rotation_xyz_from_angle() {
+        assert!(Mat4::new_rotation_x_from_angle(Deg(30.0)).approx_eq(&Mat4::new_rotation_x(f32::consts::PI / 6.0), f32::EPSILON, 2));
+        assert!(Mat4::new_rotation_y_from_angle(Deg(30.0)).approx_eq(&Mat4::new_rotation_y(f32::consts::PI / 6.0), f32::EPSILON, 2));
+        assert!(Mat4::new_rotation_z_from_angle(Rad(0.3)).approx_eq(&Mat4::new_rotation_z(0.3), f32::EPSILON, 2));
+    }
+
+    #[test]
+    fn matrix4_new_perspective_from_fov_angle() {
+        let m = Mat4::new_perspective_from_fov(60.0, 1.5, 0.1, 100.0);
+        assert!(Mat4::new_perspective_from_fov_angle(Deg(60.0), 1.5, 0.1, 100.0).approx_eq(&m, 4.0*f32::EPSILON, 4));
+        assert!(Mat4::new_perspective_from_fov_angle(Rad(f32::consts::FRAC_PI_3), 1.5, 0.1, 100.0).approx_eq(&m, 4.0*f32::EPSILON, 4));
+    }
+
+    // --------------------------------------------------------------------------
+    // Non-square matrices
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn matrix_rectangular_shape() {
+        let m = Matrix2x3::from_components_row_major(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0
+        );
+        assert_eq!(m.row(1), Vec3::new(4.0, 5.0, 6.0));
+        assert_eq!(m.col(2), Vec2::new(3.0, 6.0));
+        let t: Matrix3x2<f32> = m.transpose();
+        assert_eq!(t.row(2), Vec2::new(3.0, 6.0));
+        assert_eq!(t.transpose(), m);
+        assert_eq!(m * Vec3::new(1.0, 1.0, 1.0), Vec2::new(6.0, 15.0));
+    }
+
+    #[test]
+    fn matrix_rectangular_mul() {
+        let a = Matrix2x3::from_components_row_major(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0
+        );
+        let b: Mat2 = a * a.transpose();
+        assert_eq!(b, Mat2::from_components_row_major(14.0, 32.0, 32.0, 77.0));
+        let c: Mat3 = a.transpose() * a;
+        assert_eq!(c.row(0), Vec3::new(17.0, 22.0, 27.0));
+
+        let d = Matrix3x4::from_components_row_major(
+            1.0, 0.0, 0.0, 1.0,
+            0.0, 2.0, 0.0, 2.0,
+            0.0, 0.0, 3.0, 3.0
+        );
+        let e = Matrix4x2::from_components_row_major(
+            1.0, 2.0,
+            3.0, 4.0,
+            5.0, 6.0,
+            1.0, 1.0
+        );
+        let f: Matrix3x2<f32> = d * e;
+        assert_eq!(f, Matrix3x2::from_components_row_major(
+             2.0,  3.0,
+             8.0, 10.0,
+            18.0, 21.0
+        ));
+    }
+
+    // Checks every product of differently shaped matrices against the one of MatrixMN.
+    macro_rules! test_matrix_mul {
+        ($name:ident, $lhs:ident * $rhs:ident = $result:ident) => {
+            #[test]
+            fn $name() {
+                let a = $lhs::<i32>::from(MatrixMN::from_fn(|r, c| (5*r + 3*c) as i32 - 7));
+                let b = $rhs::<i32>::from(MatrixMN::from_fn(|r, c| (2*r + 7*c) as i32 % 11 - 4));
+                let expected = $result::from(MatrixMN::from(a) * MatrixMN::from(b));
+                assert_eq!(a * b, expected);
+            }
+        }
+    }
+
+    test_matrix_mul!(mul_matrix2_matrix2x3, Matrix2 * Matrix2x3 = Matrix2x3);
+    test_matrix_mul!(mul_matrix2_matrix2x4, Matrix2 * Matrix2x4 = Matrix2x4);
+    test_matrix_mul!(mul_matrix2x3_matrix3x2, Matrix2x3 * Matrix3x2 = Matrix2);
+    test_matrix_mul!(mul_matrix2x3_matrix3, Matrix2x3 * Matrix3 = Matrix2x3);
+    test_matrix_mul!(mul_matrix2x3_matrix3x4, Matrix2x3 * Matrix3x4 = Matrix2x4);
+    test_matrix_mul!(mul_matrix2x4_matrix4x2, Matrix2x4 * Matrix4x2 = Matrix2);
+    test_matrix_mul!(mul_matrix2x4_matrix4x3, Matrix2x4 * Matrix4x3 = Matrix2x3);
+    test_matrix_mul!(mul_matrix2x4_matrix4, Matrix2x4 * Matrix4 = Matrix2x4);
+    test_matrix_mul!(mul_matrix3x2_matrix2, Matrix3x2 * Matrix2 = Matrix3x2);
+    test_matrix_mul!(mul_matrix3x2_matrix2x3, Matrix3x2 * Matrix2x3 = Matrix3);
+    test_matrix_mul!(mul_matrix3x2_matrix2x4, Matrix3x2 * Matrix2x4 = Matrix3x4);
+    test_matrix_mul!(mul_matrix3_matrix3x2, Matrix3 * Matrix3x2 = Matrix3x2);
+    test_matrix_mul!(mul_matrix3_matrix3x4, Matrix3 * Matrix3x4 = Matrix3x4);
+    test_matrix_mul!(mul_matrix3x4_matrix4x2, Matrix3x4 * Matrix4x2 = Matrix3x2);
+    test_matrix_mul!(mul_matrix3x4_matrix4x3, Matrix3x4 * Matrix4x3 = Matrix3);
+    test_matrix_mul!(mul_matrix3x4_matrix4, Matrix3x4 * Matrix4 = Matrix3x4);
+    test_matrix_mul!(mul_matrix4x2_matrix2, Matrix4x2 * Matrix2 = Matrix4x2);
+    test_matrix_mul!(mul_matrix4x2_matrix2x3, Matrix4x2 * Matrix2x3 = Matrix4x3);
+    test_matrix_mul!(mul_matrix4x2_matrix2x4, Matrix4x2 * Matrix2x4 = Matrix4);
+    test_matrix_mul!(mul_matrix4x3_matrix3x2, Matrix4x3 * Matrix3x2 = Matrix4x2);
+    test_matrix_mul!(mul_matrix4x3_matrix3, Matrix4x3 * Matrix3 = Matrix4x3);
+    test_matrix_mul!(mul_matrix4x3_matrix3x4, Matrix4x3 * Matrix3x4 = Matrix4);
+    test_matrix_mul!(mul_matrix4_matrix4x2, Matrix4 * Matrix4x2 = Matrix4x2);
+    test_matrix_mul!(mul_matrix4_matrix4x3, Matrix4 * Matrix4x3 = Matrix4x3);
+
+    #[test]
+    fn matrix3x4_matrix4_round_trip() {
+        let m = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation_x(0.3);
+        let packed = Matrix3x4::from(m);
+        assert_eq!(packed.row(0), m.row(0));
+        assert_eq!(Mat4::from(packed), m);
+        let p = Vec4::new(1.0, -1.0, 2.0, 1.0);
+        let q = packed * p;
+        assert_eq!(Vec4::new(q.x, q.y, q.z, 1.0), m * p);
+    }
+
+    // --------------------------------------------------------------------------
+    // Bulk transformations
+    // --------------------------------------------------------------------------
+
+    fn points() -> Vec<Vec3> {
+        (0..7).map(|i| Vec3::new(i as f32, 1.0 - i as f32, 0.5 * i as f32 - 4.0)).collect()
+    }
+
+    #[test]
+    fn matrix4_transform_points() {
+        let m = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation_y(0.3) * Mat4::new_scale(2.0, 2.0, 2.0);
+        let src = points();
+        let mut dst = vec![Vec3::zero(); src.len()];
+        m.transform_points(&src, &mut dst);
+        let mut in_place = src.clone();
+        m.transform_points_in_place(&mut in_place);
+        for (i, p) in src.iter().enumerate() {
+            let expected = m.transform_point(Pnt3::from_vector(*p)).to_vector();
+            assert!(dst[i].approx_eq(&expected, EPS, 2));
+            assert_eq!(in_place[i], dst[i]);
+        }
+    }
+
+    #[test]
+    fn matrix4_transform_points_perspective() {
+        let m = Mat4::new_perspective_from_fov(60.0, 1.5, 0.1, 100.0);
+        let src = points();
+        let mut dst = vec![Vec3::zero(); src.len()];
+        m.transform_points(&src, &mut dst);
+        for (i, p) in src.iter().enumerate() {
+            let expected = (m * Vector4::new(p.x, p.y, p.z, 1.0)).wdiv();
+            assert!(dst[i].approx_eq(&expected, EPS, 2));
+        }
+    }
+
+    #[test]
+    fn matrix4_transform_vectors() {
+        let m = Mat4::new_translation(1.0, 2.0, 3.0) * Mat4::new_rotation_x(-0.7);
+        let src = points();
+        let mut dst = vec![Vec3::zero(); src.len()];
+        m.transform_vectors(&src, &mut dst);
+        let mut in_place = src.clone();
+        m.transform_vectors_in_place(&mut in_place);
+        for (i, v) in src.iter().enumerate() {
+            assert_eq!(dst[i], m.transform_vector(*v));
+            assert_eq!(in_place[i], dst[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix4_transform_points_length_mismatch() {
+        let mut dst = [Vec3::zero(); 2];
+        Mat4::identity().transform_points(&points(), &mut dst);
+    }
+
+    #[test]
+    fn matrix3_transform_points() {
+        let m = Mat3::from_components_row_major(
+            0.0, -1.0, 5.0,
+            1.0,  0.0, 6.0,
+            0.0,  0.5, 2.0
+        );
+        let src = [Vec2::new(1.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(-3.0, 4.0)];
+        let mut dst = [Vec2::zero(); 3];
+        m.transform_points(&src, &mut dst);
+        let mut vectors = src;
+        m.transform_vectors_in_place(&mut vectors);
+        for i in 0..3 {
+            assert!(dst[i].approx_eq(&m.transform_point(Pnt2::from_vector(src[i])).to_vector(), EPS, 2));
+            assert_eq!(vectors[i], m.transform_vector(src[i]));
+        }
     }
-}
\ No newline at end of file
+}
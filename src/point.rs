@@ -0,0 +1,200 @@
+use std::ops::{Add,AddAssign,Sub,SubAssign};
+
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// Points are positions in affine space. The difference of two points is a vector, a vector can be
+// added to a point, but two points cannot be added.
+macro_rules! implement_point {
+    ($type:ident {
+        vector_type: $vector_type:ident,
+        elems: { $($num:expr => $member:ident),+ }
+    }) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+        pub struct $type<T> where T: Base {
+            $(pub $member: T),*
+        }
+
+        impl<T: Base> $type<T> {
+            pub fn new($($member: T),*) -> Self {
+                $type { $($member: $member),* }
+            }
+
+            #[inline]
+            pub fn origin() -> Self {
+                $type { $($member: T::zero()),* }
+            }
+
+            #[inline]
+            pub fn from_vector(v: $vector_type<T>) -> Self {
+                $type { $($member: v.$member),* }
+            }
+
+            // The vector from the origin to the point.
+            #[inline]
+            pub fn to_vector(self) -> $vector_type<T> {
+                $vector_type::new($(self.$member),*)
+            }
+        }
+
+        impl<T: BaseFloat> $type<T> {
+            #[inline]
+            pub fn distance(self, other: Self) -> T {
+                (other - self).length()
+            }
+
+            // Linear interpolation between two points. This is an affine combination and therefore
+            // allowed even though points cannot be added.
+            #[inline]
+            pub fn lerp(self, other: Self, t: T) -> Self {
+                self + (other - self) * t
+            }
+        }
+
+        impl<T> Default for $type<T>
+            where T: Base
+        {
+            fn default() -> Self {
+                $type::origin()
+            }
+        }
+
+        impl<T: Base> Elem<usize> for $type<T> {
+            type Output = T;
+            #[inline]
+            fn elem(self, index: usize) -> Self::Output {
+                match index {
+                    $($num => self.$member,)*
+                    _ => panic!("index out of range")
+                }
+            }
+        }
+
+        impl<T: BaseFloat> ApproxEq for $type<T>
+            where T: ApproxEq<Flt=T>
+        {
+            type Flt = T;
+            #[inline]
+            fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+                $(self.$member.approx_eq(&other.$member, epsilon, ulps))&&*
+            }
+        }
+
+        // p - p
+        implement_binary_operator!(Sub<$type<T>> for $type<T>,
+            fn sub(lhs, rhs) -> $vector_type<T> {
+                $vector_type::new( $(lhs.$member - rhs.$member),* )
+            }
+        );
+
+        // p + v
+        implement_binary_operator!(Add<$vector_type<T>> for $type<T>,
+            fn add(point, vector) -> $type<T> {
+                $type::new( $(point.$member + vector.$member),* )
+            }
+        );
+
+        // p += v
+        implement_binary_assign_operator!(AddAssign<$vector_type<T>> for $type<T>,
+            fn add_assign(point, vector) {{
+                $(point.$member += vector.$member;)*
+            }}
+        );
+
+        // p - v
+        implement_binary_operator!(Sub<$vector_type<T>> for $type<T>,
+            fn sub(point, vector) -> $type<T> {
+                $type::new( $(point.$member - vector.$member),* )
+            }
+        );
+
+        // p -= v
+        implement_binary_assign_operator!(SubAssign<$vector_type<T>> for $type<T>,
+            fn sub_assign(point, vector) {{
+                $(point.$member -= vector.$member;)*
+            }}
+        );
+
+        // p cw_min p
+        implement_binary_operator!(CwMin<$type<T>> for $type<T>,
+            fn cw_min(lhs, rhs) -> $type<T> {
+                $type::new( $(if lhs.$member < rhs.$member { lhs.$member } else { rhs.$member }),* )
+            }
+        );
+
+        // p cw_max p
+        implement_binary_operator!(CwMax<$type<T>> for $type<T>,
+            fn cw_max(lhs, rhs) -> $type<T> {
+                $type::new( $(if rhs.$member < lhs.$member { lhs.$member } else { rhs.$member }),* )
+            }
+        );
+    }
+}
+
+implement_point!(Point2 { vector_type: Vector2, elems: { 0 => x, 1 => y } });
+implement_point!(Point3 { vector_type: Vector3, elems: { 0 => x, 1 => y, 2 => z } });
+
+impl<T: Base> Point2<T> {
+    // Homogeneous coordinates with w = 1.
+    #[inline]
+    pub fn to_homogeneous(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, T::one())
+    }
+
+    #[inline]
+    pub fn from_homogeneous(v: Vector3<T>) -> Self {
+        Point2::new(v.x / v.z, v.y / v.z)
+    }
+}
+
+impl<T: Base> Point3<T> {
+    // Homogeneous coordinates with w = 1.
+    #[inline]
+    pub fn to_homogeneous(self) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, T::one())
+    }
+
+    #[inline]
+    pub fn from_homogeneous(v: Vector4<T>) -> Self {
+        Point3::from_vector(v.wdiv())
+    }
+}
+
+impl<T: Base> Matrix3<T> {
+    // Transforms a 2D point including the translation and the perspective divide.
+    pub fn transform_point(&self, p: Point2<T>) -> Point2<T> {
+        Point2::from_homogeneous(self * p.to_homogeneous())
+    }
+
+    // Transforms a 2D direction. The translation is ignored.
+    pub fn transform_vector(&self, v: Vector2<T>) -> Vector2<T> {
+        Vector2::new(
+            self.m00 * v.x + self.m01 * v.y,
+            self.m10 * v.x + self.m11 * v.y,
+        )
+    }
+}
+
+impl<T: Base> Matrix4<T> {
+    // Transforms a point including the translation and the perspective divide.
+    pub fn transform_point(&self, p: Point3<T>) -> Point3<T> {
+        Point3::from_homogeneous(self * p.to_homogeneous())
+    }
+
+    // Transforms a direction. The translation is ignored.
+    pub fn transform_vector(&self, v: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            self.m00 * v.x + self.m01 * v.y + self.m02 * v.z,
+            self.m10 * v.x + self.m11 * v.y + self.m12 * v.z,
+            self.m20 * v.x + self.m21 * v.y + self.m22 * v.z,
+        )
+    }
+}
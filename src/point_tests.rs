@@ -0,0 +1,114 @@
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use crate::point::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+    use crate::traits::*;
+
+    type Pnt2 = Point2<f32>;
+    type Pnt3 = Point3<f32>;
+
+    type Mat3 = Matrix3<f32>;
+    type Mat4 = Matrix4<f32>;
+
+    type Vec2 = Vector2<f32>;
+    type Vec3 = Vector3<f32>;
+
+    const EPS: f32 = 4.0*f32::EPSILON;
+
+    // --------------------------------------------------------------------------
+    // Affine operations
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn point3_sub_point() {
+        assert_eq!(Pnt3::new(4.0, 5.0, 6.0) - Pnt3::new(1.0, 1.0, 1.0), Vec3::new(3.0, 4.0, 5.0));
+        assert_eq!(&Pnt3::new(4.0, 5.0, 6.0) - &Pnt3::new(1.0, 1.0, 1.0), Vec3::new(3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn point3_add_vector() {
+        assert_eq!(Pnt3::new(1.0, 2.0, 3.0) + Vec3::new(1.0, 1.0, 1.0), Pnt3::new(2.0, 3.0, 4.0));
+        let mut p = Pnt3::origin();
+        p += Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(p, Pnt3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn point3_sub_vector() {
+        assert_eq!(Pnt3::new(1.0, 2.0, 3.0) - Vec3::new(1.0, 1.0, 1.0), Pnt3::new(0.0, 1.0, 2.0));
+        let mut p = Pnt3::new(1.0, 2.0, 3.0);
+        p -= Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(p, Pnt3::origin());
+    }
+
+    #[test]
+    fn point2_affine() {
+        let a = Pnt2::new(1.0, 2.0);
+        let b = a + Vec2::new(3.0, 4.0);
+        assert_eq!(b - a, Vec2::new(3.0, 4.0));
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(a.lerp(b, 0.5), Pnt2::new(2.5, 4.0));
+    }
+
+    #[test]
+    fn point3_vector_conversion() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(Pnt3::from_vector(v).to_vector(), v);
+        assert_eq!(Pnt3::new(1.0, 2.0, 3.0).elem(2), 3.0);
+    }
+
+    #[test]
+    fn point3_cw_min_max() {
+        let a = Pnt3::new(1.0, 5.0, 3.0);
+        let b = Pnt3::new(4.0, 2.0, 6.0);
+        assert_eq!(a.cw_min(b), Pnt3::new(1.0, 2.0, 3.0));
+        assert_eq!(a.cw_max(b), Pnt3::new(4.0, 5.0, 6.0));
+    }
+
+    #[test]
+    fn point_integer() {
+        let p = Point2::new(1, 2) + Vector2::new(3, 4);
+        assert_eq!(p, Point2::new(4, 6));
+        assert_eq!(p - Point2::new(1, 1), Vector2::new(3, 5));
+    }
+
+    // --------------------------------------------------------------------------
+    // Matrix transformations
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn matrix4_transform_point_translation() {
+        let m = Mat4::new_translation(1.0, 2.0, 3.0);
+        assert_eq!(m.transform_point(Pnt3::new(1.0, 1.0, 1.0)), Pnt3::new(2.0, 3.0, 4.0));
+        assert_eq!(m.transform_vector(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn matrix4_transform_rotation() {
+        let m = Mat4::new_translation(0.0, 0.0, 5.0) * Mat4::new_rotation_z(std::f32::consts::FRAC_PI_2);
+        assert!(m.transform_point(Pnt3::new(1.0, 0.0, 0.0)).approx_eq(&Pnt3::new(0.0, 1.0, 5.0), EPS, 2));
+        assert!(m.transform_vector(Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(0.0, 1.0, 0.0), EPS, 2));
+    }
+
+    #[test]
+    fn matrix4_transform_point_perspective() {
+        let m = Mat4::new_perspective_from_bounds(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+        let near = m.transform_point(Pnt3::new(1.0, 1.0, -1.0));
+        let far = m.transform_point(Pnt3::new(10.0, -10.0, -10.0));
+        assert!(near.approx_eq(&Pnt3::new(1.0, 1.0, -1.0), EPS, 2));
+        assert!(far.approx_eq(&Pnt3::new(1.0, -1.0, 1.0), EPS, 2));
+    }
+
+    #[test]
+    fn matrix3_transform_2d() {
+        let m = Mat3::from_components_row_major(
+            0.0, -1.0, 5.0,
+            1.0,  0.0, 6.0,
+            0.0,  0.0, 1.0
+        );
+        assert_eq!(m.transform_point(Pnt2::new(1.0, 0.0)), Pnt2::new(5.0, 7.0));
+        assert_eq!(m.transform_vector(Vec2::new(1.0, 0.0)), Vec2::new(0.0, 1.0));
+    }
+}
@@ -0,0 +1,373 @@
+use std::ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg};
+
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// The vector part is stored in (x, y, z) and the scalar part in w.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Quaternion<T: BaseFloat> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: BaseFloat> Quaternion<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Quaternion { x, y, z, w }
+    }
+
+    pub fn from_vector_scalar(v: Vector3<T>, s: T) -> Self {
+        Quaternion::new(v.x, v.y, v.z, s)
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    pub fn zero() -> Self {
+        Quaternion::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    // Same convention as Matrix4::new_rotation. The axis is expected to be normalized.
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Self {
+        let two = T::one() + T::one();
+        let (sin_h, cos_h) = T::sin_cos(angle / two);
+        Quaternion::from_vector_scalar(axis * sin_h, cos_h)
+    }
+
+    pub fn from_rotation_x(angle: T) -> Self {
+        Quaternion::from_axis_angle(Vector3::new(T::one(), T::zero(), T::zero()), angle)
+    }
+
+    pub fn from_rotation_y(angle: T) -> Self {
+        Quaternion::from_axis_angle(Vector3::new(T::zero(), T::one(), T::zero()), angle)
+    }
+
+    pub fn from_rotation_z(angle: T) -> Self {
+        Quaternion::from_axis_angle(Vector3::new(T::zero(), T::zero(), T::one()), angle)
+    }
+
+    // Equivalent to Matrix4::new_rotation_x(x) * Matrix4::new_rotation_y(y) * Matrix4::new_rotation_z(z).
+    pub fn from_euler(x: T, y: T, z: T) -> Self {
+        Quaternion::from_rotation_x(x) * Quaternion::from_rotation_y(y) * Quaternion::from_rotation_z(z)
+    }
+
+    #[inline]
+    pub fn vector(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    pub fn scalar(&self) -> T {
+        self.w
+    }
+
+    #[inline]
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub fn length(&self) -> T {
+        T::sqrt(self.length_squared())
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self / self.length()
+    }
+
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.length_squared();
+        // Exact zero on purpose: any other quaternion has an inverse, it only gets large
+        if n == T::zero() {
+            None
+        } else {
+            Some(self.conjugate() / n)
+        }
+    }
+
+    // Returns the rotation axis and angle of a unit quaternion.
+    pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+        let two = T::one() + T::one();
+        let sin_h = self.vector().length();
+        if sin_h == T::zero() {
+            (Vector3::new(T::one(), T::zero(), T::zero()), T::zero())
+        } else {
+            (self.vector() / sin_h, two * T::atan2(sin_h, self.w))
+        }
+    }
+
+    pub fn exp(&self) -> Self {
+        let e = T::exp(self.w);
+        let n = self.vector().length();
+        if n == T::zero() {
+            Quaternion::new(T::zero(), T::zero(), T::zero(), e)
+        } else {
+            let (sin_n, cos_n) = T::sin_cos(n);
+            Quaternion::from_vector_scalar(self.vector() * (e * sin_n / n), e * cos_n)
+        }
+    }
+
+    pub fn ln(&self) -> Self {
+        let n = self.vector().length();
+        let w = T::ln(self.length());
+        if n == T::zero() {
+            Quaternion::new(T::zero(), T::zero(), T::zero(), w)
+        } else {
+            Quaternion::from_vector_scalar(self.vector() * (T::atan2(n, self.w) / n), w)
+        }
+    }
+
+    // Flips `other` into the hemisphere of `self` so that interpolating between both takes the
+    // shortest path.
+    #[inline]
+    fn closest(&self, other: Self) -> Self {
+        if self.dot(other) < T::zero() { -other } else { other }
+    }
+
+    // Normalized linear interpolation along the shortest path. Cheaper than slerp but does not
+    // have a constant angular velocity.
+    pub fn nlerp(&self, other: Self, t: T) -> Self {
+        let other = self.closest(other);
+        (self * (T::one() - t) + other * t).normalize()
+    }
+
+    // Spherical linear interpolation along the shortest path.
+    pub fn slerp(&self, other: Self, t: T) -> Self {
+        self.slerp_no_flip(self.closest(other), t)
+    }
+
+    fn slerp_no_flip(&self, other: Self, t: T) -> Self {
+        let cos_theta = self.dot(other);
+        // For (nearly) parallel inputs sin(theta) approaches zero and nlerp is the stable choice.
+        if T::abs(cos_theta) > T::from(0.9995).unwrap() {
+            return (self * (T::one() - t) + other * t).normalize();
+        }
+        let sin_theta = T::sqrt(T::one() - cos_theta * cos_theta);
+        let theta = T::atan2(sin_theta, cos_theta);
+        let a = T::sin((T::one() - t) * theta) / sin_theta;
+        let b = T::sin(t * theta) / sin_theta;
+        self * a + other * b
+    }
+
+    // Spherical cubic interpolation between `self` and `other`. The tangents `a` and `b` are
+    // usually computed with Quaternion::squad_control_point.
+    pub fn squad(&self, a: Self, b: Self, other: Self, t: T) -> Self {
+        let two = T::one() + T::one();
+        let other = self.closest(other);
+        let a = self.closest(a);
+        let b = other.closest(b);
+        self.slerp_no_flip(other, t).slerp_no_flip(a.slerp_no_flip(b, t), two * t * (T::one() - t))
+    }
+
+    // Computes the squad tangent for the key `current` given its neighbouring keys.
+    pub fn squad_control_point(previous: Self, current: Self, next: Self) -> Self {
+        let four = T::from(4.0).unwrap();
+        let inv = current.conjugate();
+        let previous = current.closest(previous);
+        let next = current.closest(next);
+        let l = (inv * next).ln() + (inv * previous).ln();
+        current * (-l / four).exp()
+    }
+}
+
+// q + q
+implement_binary_operator!(Add<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn add(lhs, rhs) -> Quaternion<T> {
+        Quaternion::new(lhs.x + rhs.x, lhs.y + rhs.y, lhs.z + rhs.z, lhs.w + rhs.w)
+    }
+);
+
+// q += q
+implement_binary_assign_operator!(AddAssign<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn add_assign(lhs, rhs) {{
+        lhs.x += rhs.x; lhs.y += rhs.y; lhs.z += rhs.z; lhs.w += rhs.w;
+    }}
+);
+
+// q - q
+implement_binary_operator!(Sub<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn sub(lhs, rhs) -> Quaternion<T> {
+        Quaternion::new(lhs.x - rhs.x, lhs.y - rhs.y, lhs.z - rhs.z, lhs.w - rhs.w)
+    }
+);
+
+// q -= q
+implement_binary_assign_operator!(SubAssign<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn sub_assign(lhs, rhs) {{
+        lhs.x -= rhs.x; lhs.y -= rhs.y; lhs.z -= rhs.z; lhs.w -= rhs.w;
+    }}
+);
+
+// q * q (Hamilton product)
+implement_binary_operator!(Mul<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn mul(a, b) -> Quaternion<T> {
+        Quaternion::new(
+            a.w*b.x + a.x*b.w + a.y*b.z - a.z*b.y,
+            a.w*b.y - a.x*b.z + a.y*b.w + a.z*b.x,
+            a.w*b.z + a.x*b.y - a.y*b.x + a.z*b.w,
+            a.w*b.w - a.x*b.x - a.y*b.y - a.z*b.z,
+        )
+    }
+);
+
+// q *= q
+implement_binary_assign_operator!(MulAssign<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn mul_assign(lhs, rhs) {{
+        *lhs = *lhs * rhs;
+    }}
+);
+
+// q * s
+implement_binary_operator!(Mul<T> for Quaternion<T> where T: BaseFloat,
+    fn mul(q, s) -> Quaternion<T> {
+        Quaternion::new(q.x * s, q.y * s, q.z * s, q.w * s)
+    }
+);
+
+// q *= s
+implement_binary_assign_operator!(MulAssign<T> for Quaternion<T> where T: BaseFloat,
+    fn mul_assign(q, s) {{
+        q.x *= s; q.y *= s; q.z *= s; q.w *= s;
+    }}
+);
+
+// q / s
+implement_binary_operator!(Div<T> for Quaternion<T> where T: BaseFloat,
+    fn div(q, s) -> Quaternion<T> {
+        Quaternion::new(q.x / s, q.y / s, q.z / s, q.w / s)
+    }
+);
+
+// q /= s
+implement_binary_assign_operator!(DivAssign<T> for Quaternion<T> where T: BaseFloat,
+    fn div_assign(q, s) {{
+        q.x /= s; q.y /= s; q.z /= s; q.w /= s;
+    }}
+);
+
+// q * v (rotates v by the unit quaternion q)
+implement_binary_operator!(Mul<Vector3<T>> for Quaternion<T> where T: BaseFloat,
+    fn mul(q, v) -> Vector3<T> {{
+        let two = T::one() + T::one();
+        let u = q.vector();
+        let t = u.cross(v) * two;
+        v + t * q.w + u.cross(t)
+    }}
+);
+
+// q dot q
+implement_binary_operator!(Dot<Quaternion<T>> for Quaternion<T> where T: BaseFloat,
+    fn dot(a, b) -> T {
+        a.x*b.x + a.y*b.y + a.z*b.z + a.w*b.w
+    }
+);
+
+implement_unary_operator!(Neg for Quaternion<T> where T: BaseFloat,
+    fn neg(q) -> Quaternion<T> {
+        Quaternion::new(-q.x, -q.y, -q.z, -q.w)
+    }
+);
+
+impl<T: BaseFloat> Default for Quaternion<T> {
+    fn default() -> Self {
+        Quaternion::identity()
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Quaternion<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.x.approx_eq(&other.x, epsilon, ulps) &&
+        self.y.approx_eq(&other.y, epsilon, ulps) &&
+        self.z.approx_eq(&other.z, epsilon, ulps) &&
+        self.w.approx_eq(&other.w, epsilon, ulps)
+    }
+}
+
+impl<T: BaseFloat + ApproxEq<Flt=T>> Quaternion<T> {
+    // Compares unit quaternions as rotations: q and -q describe the same rotation, so other is
+    // flipped into the hemisphere of self (non-negative dot product) before comparing components.
+    pub fn approx_eq_rotation(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        let other = if self.dot(*other) < T::zero() { -*other } else { *other };
+        self.approx_eq(&other, epsilon, ulps)
+    }
+}
+
+// --------------------------------------------------------------------------
+// Conversions
+// --------------------------------------------------------------------------
+
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix3<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        let two = T::one() + T::one();
+        let (xx, yy, zz) = (q.x * q.x, q.y * q.y, q.z * q.z);
+        let (xy, xz, yz) = (q.x * q.y, q.x * q.z, q.y * q.z);
+        let (wx, wy, wz) = (q.w * q.x, q.w * q.y, q.w * q.z);
+        Matrix3::from_components_row_major(
+            T::one() - two*(yy + zz), two*(xy - wz)           , two*(xz + wy)           ,
+            two*(xy + wz)           , T::one() - two*(xx + zz), two*(yz - wx)           ,
+            two*(xz - wy)           , two*(yz + wx)           , T::one() - two*(xx + yy)
+        )
+    }
+}
+
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix4<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        let m = Matrix3::from(q);
+        Matrix4::from_components_row_major(
+            m.m00    , m.m01    , m.m02    , T::zero(),
+            m.m10    , m.m11    , m.m12    , T::zero(),
+            m.m20    , m.m21    , m.m22    , T::zero(),
+            T::zero(), T::zero(), T::zero(), T::one()
+        )
+    }
+}
+
+// The matrix is expected to be a pure rotation.
+impl<T: BaseFloat> From<Matrix3<T>> for Quaternion<T> {
+    fn from(m: Matrix3<T>) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let quarter = one / (two + two);
+        let trace = m.m00 + m.m11 + m.m22;
+        if trace > T::zero() {
+            let s = T::sqrt(trace + one) * two;
+            Quaternion::new((m.m21 - m.m12) / s, (m.m02 - m.m20) / s, (m.m10 - m.m01) / s, quarter * s)
+        } else if m.m00 > m.m11 && m.m00 > m.m22 {
+            let s = T::sqrt(one + m.m00 - m.m11 - m.m22) * two;
+            Quaternion::new(quarter * s, (m.m01 + m.m10) / s, (m.m02 + m.m20) / s, (m.m21 - m.m12) / s)
+        } else if m.m11 > m.m22 {
+            let s = T::sqrt(one + m.m11 - m.m00 - m.m22) * two;
+            Quaternion::new((m.m01 + m.m10) / s, quarter * s, (m.m12 + m.m21) / s, (m.m02 - m.m20) / s)
+        } else {
+            let s = T::sqrt(one + m.m22 - m.m00 - m.m11) * two;
+            Quaternion::new((m.m02 + m.m20) / s, (m.m12 + m.m21) / s, quarter * s, (m.m10 - m.m01) / s)
+        }
+    }
+}
+
+// Only the upper-left 3x3 part is used and it is expected to be a pure rotation.
+impl<T: BaseFloat> From<Matrix4<T>> for Quaternion<T> {
+    fn from(m: Matrix4<T>) -> Self {
+        Quaternion::from(Matrix3::from(m))
+    }
+}
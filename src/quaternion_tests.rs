@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests {
+    use crate::quaternion::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+
+    use std::f32::consts::{PI,FRAC_PI_2};
+
+    type Quat = Quaternion<f32>;
+
+    type Mat3 = Matrix3<f32>;
+    type Mat4 = Matrix4<f32>;
+
+    type Vec3 = Vector3<f32>;
+    type Vec4 = Vector4<f32>;
+
+    const EPS: f32 = 4.0*f32::EPSILON;
+
+    // --------------------------------------------------------------------------
+    // Contructors
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_identity() {
+        assert_eq!(Quat::identity(), Quat::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Quat::default(), Quat::identity());
+    }
+
+    #[test]
+    fn quaternion_from_axis_angle() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), PI);
+        assert!(q.approx_eq(&Quat::new(0.0, 0.0, 1.0, 0.0), EPS, 2));
+    }
+
+    #[test]
+    fn quaternion_from_euler() {
+        let (x, y, z) = (0.3, -1.2, 2.1);
+        let m = Mat4::new_rotation_x(x) * Mat4::new_rotation_y(y) * Mat4::new_rotation_z(z);
+        assert!(Mat4::from(Quat::from_euler(x, y, z)).approx_eq(&m, EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_to_axis_angle() {
+        let axis = Vec3::new(1.0, 2.0, 3.0).normalize();
+        let (a, angle) = Quat::from_axis_angle(axis, 1.3).to_axis_angle();
+        assert!(a.approx_eq(&axis, EPS, 2));
+        assert!(angle.approx_eq(&1.3, EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Algebra
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_mul_basis() {
+        let i = Quat::new(1.0, 0.0, 0.0, 0.0);
+        let j = Quat::new(0.0, 1.0, 0.0, 0.0);
+        let k = Quat::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(i * j, k);
+        assert_eq!(j * k, i);
+        assert_eq!(k * i, j);
+        assert_eq!(i * i, -Quat::identity());
+        assert_eq!(i * j * k, -Quat::identity());
+    }
+
+    #[test]
+    fn quaternion_mul_assign() {
+        let mut q = Quat::from_rotation_x(0.5);
+        q *= Quat::from_rotation_x(0.25);
+        assert!(q.approx_eq(&Quat::from_rotation_x(0.75), EPS, 2));
+    }
+
+    #[test]
+    fn quaternion_add_sub_scalar() {
+        let a = Quat::new(1.0, 2.0, 3.0, 4.0);
+        let b = Quat::new(4.0, 3.0, 2.0, 1.0);
+        assert_eq!(a + b, Quat::new(5.0, 5.0, 5.0, 5.0));
+        assert_eq!(a - b, Quat::new(-3.0, -1.0, 1.0, 3.0));
+        assert_eq!(a * 2.0, Quat::new(2.0, 4.0, 6.0, 8.0));
+        assert_eq!(a / 2.0, Quat::new(0.5, 1.0, 1.5, 2.0));
+    }
+
+    #[test]
+    fn quaternion_conjugate() {
+        assert_eq!(Quat::new(1.0, 2.0, 3.0, 4.0).conjugate(), Quat::new(-1.0, -2.0, -3.0, 4.0));
+    }
+
+    #[test]
+    fn quaternion_inverse() {
+        let q = Quat::new(1.0, 2.0, 3.0, 4.0);
+        assert!((q * q.inverse().unwrap()).approx_eq(&Quat::identity(), EPS, 2));
+        assert!(Quat::zero().inverse().is_none());
+    }
+
+    #[test]
+    fn quaternion_normalize() {
+        let q = Quat::new(1.0, 2.0, 3.0, 4.0).normalize();
+        assert!(q.length().approx_eq(&1.0, EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Rotation
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_mul_vector() {
+        let q = Quat::from_rotation_z(FRAC_PI_2);
+        assert!((q * Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(0.0, 1.0, 0.0), EPS, 2));
+    }
+
+    #[test]
+    fn quaternion_mul_vector_matches_matrix() {
+        let axis = Vec3::new(-1.0, 0.5, 2.0).normalize();
+        let q = Quat::from_axis_angle(axis, 0.7);
+        let m = Mat4::new_rotation(axis, 0.7);
+        let v = Vec3::new(3.0, -2.0, 1.0);
+        let r = m * Vec4::new(v.x, v.y, v.z, 1.0);
+        assert!((q * v).approx_eq(&Vec3::new(r.x, r.y, r.z), EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_composition_has_no_drift() {
+        let step = Quat::from_rotation_y(2.0 * PI / 1000.0);
+        let mut q = Quat::identity();
+        for _ in 0..1000 {
+            q = (q * step).normalize();
+        }
+        assert!(q.length().approx_eq(&1.0, EPS, 2));
+        assert!((q * Vec3::new(1.0, 0.0, 0.0)).approx_eq(&Vec3::new(1.0, 0.0, 0.0), 1e-4, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Conversions
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_to_matrix4() {
+        let axis = Vec3::new(1.0, 1.0, 0.0).normalize();
+        let q = Quat::from_axis_angle(axis, 1.1);
+        assert!(Mat4::from(q).approx_eq(&Mat4::new_rotation(axis, 1.1), EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_from_matrix_round_trip() {
+        for &(x, y, z) in &[(0.1, 0.2, 0.3), (PI, 0.0, 0.0), (0.0, PI, 0.0), (0.0, 0.0, PI), (2.5, -1.0, 3.0)] {
+            let q = Quat::from_euler(x, y, z);
+            let r = Quat::from(Mat3::from(q));
+            assert!(r.approx_eq_rotation(&q, EPS, 4), "{:?} != {:?}", r, q);
+            let r = Quat::from(Mat4::from(q));
+            assert!(r.approx_eq_rotation(&q, EPS, 4), "{:?} != {:?}", r, q);
+        }
+    }
+
+    // --------------------------------------------------------------------------
+    // ApproxEq
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_approx_eq_sign() {
+        let q = Quat::from_rotation_x(0.4);
+        assert!(!q.approx_eq(&-q, EPS, 2));
+        assert!(q.approx_eq_rotation(&-q, EPS, 2));
+        assert!(!q.approx_eq_rotation(&Quat::from_rotation_x(0.5), EPS, 2));
+    }
+
+    // --------------------------------------------------------------------------
+    // Interpolation
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn quaternion_slerp_endpoints() {
+        let a = Quat::from_rotation_y(0.2);
+        let b = Quat::from_rotation_y(1.4);
+        assert!(a.slerp(b, 0.0).approx_eq_rotation(&a, EPS, 2));
+        assert!(a.slerp(b, 1.0).approx_eq_rotation(&b, EPS, 2));
+    }
+
+    #[test]
+    fn quaternion_slerp_constant_velocity() {
+        let a = Quat::from_rotation_z(0.0);
+        let b = Quat::from_rotation_z(2.0);
+        for &t in &[0.1, 0.25, 0.5, 0.9] {
+            assert!(a.slerp(b, t).approx_eq_rotation(&Quat::from_rotation_z(2.0 * t), EPS, 4));
+        }
+    }
+
+    #[test]
+    fn quaternion_slerp_shortest_path() {
+        let a = Quat::from_rotation_z(0.1);
+        let b = -Quat::from_rotation_z(0.5);
+        assert!(a.slerp(b, 0.5).approx_eq_rotation(&Quat::from_rotation_z(0.3), EPS, 4));
+        assert!(a.nlerp(b, 0.5).approx_eq_rotation(&Quat::from_rotation_z(0.3), EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_slerp_near_parallel() {
+        let a = Quat::from_rotation_x(1.0);
+        let b = Quat::from_rotation_x(1.0 + 1e-6);
+        let q = a.slerp(b, 0.5);
+        assert!(q.x.is_finite() && q.y.is_finite() && q.z.is_finite() && q.w.is_finite());
+        assert!(q.approx_eq_rotation(&a, 1e-5, 2));
+        assert!(a.slerp(a, 0.3).approx_eq_rotation(&a, EPS, 2));
+    }
+
+    #[test]
+    fn quaternion_slerp_matrix_rotations() {
+        let a = Quat::from(Mat4::new_rotation_x(0.5));
+        let b = Quat::from(Mat4::new_rotation_x(1.5));
+        assert!(Mat4::from(a.slerp(b, 0.5)).approx_eq(&Mat4::new_rotation_x(1.0), EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_exp_ln() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), 1.2);
+        assert!(q.ln().exp().approx_eq(&q, EPS, 4));
+    }
+
+    #[test]
+    fn quaternion_squad_endpoints() {
+        let q0 = Quat::from_rotation_x(0.0);
+        let q1 = Quat::from_rotation_x(0.5);
+        let q2 = Quat::from_rotation_y(1.0);
+        let q3 = Quat::from_rotation_z(1.5);
+        let a = Quat::squad_control_point(q0, q1, q2);
+        let b = Quat::squad_control_point(q1, q2, q3);
+        assert!(q1.squad(a, b, q2, 0.0).approx_eq_rotation(&q1, EPS, 4));
+        assert!(q1.squad(a, b, q2, 1.0).approx_eq_rotation(&q2, EPS, 4));
+        assert!(q1.squad(a, b, q2, 0.5).length().approx_eq(&1.0, 1e-5, 2));
+    }
+
+    #[test]
+    fn quaternion_squad_collinear_is_slerp() {
+        let q: Vec<Quat> = (0..4).map(|i| Quat::from_rotation_y(0.4 * i as f32)).collect();
+        let a = Quat::squad_control_point(q[0], q[1], q[2]);
+        let b = Quat::squad_control_point(q[1], q[2], q[3]);
+        assert!(q[1].squad(a, b, q[2], 0.3).approx_eq_rotation(&q[1].slerp(q[2], 0.3), 1e-5, 4));
+    }
+}
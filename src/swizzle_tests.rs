@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::vector::*;
+
+    type Vec2 = Vector2<f32>;
+    type Vec3 = Vector3<f32>;
+    type Vec4 = Vector4<f32>;
+
+    // --------------------------------------------------------------------------
+    // Getters
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn swizzle_vector2() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.yx(), Vec2::new(2.0, 1.0));
+        assert_eq!(v.xyx(), Vec3::new(1.0, 2.0, 1.0));
+        assert_eq!(v.yyxx(), Vec4::new(2.0, 2.0, 1.0, 1.0));
+        assert_eq!(v.xy0(), Vec3::new(1.0, 2.0, 0.0));
+        assert_eq!(v.xy1(), Vec3::new(1.0, 2.0, 1.0));
+        assert_eq!(v.yxy1(), Vec4::new(2.0, 1.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn swizzle_vector3() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.xy(), Vec2::new(1.0, 2.0));
+        assert_eq!(v.zyx(), Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(v.xxyy(), Vec4::new(1.0, 1.0, 2.0, 2.0));
+        assert_eq!(v.xyz0(), Vec4::new(1.0, 2.0, 3.0, 0.0));
+        assert_eq!(v.xyz1(), Vec4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(v.zx0(), Vec3::new(3.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn swizzle_vector4() {
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.xyz(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.wz(), Vec2::new(4.0, 3.0));
+        assert_eq!(v.wzyx(), Vec4::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(v.xyzw(), v);
+        assert_eq!(v.www1(), Vec4::new(4.0, 4.0, 4.0, 1.0));
+        // The homogeneous point of the truncated vector
+        assert_eq!(v.xyz().xyz1(), Vec4::new(1.0, 2.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn swizzle_integer() {
+        let v = Vector3::new(1, 2, 3);
+        assert_eq!(v.zzx(), Vector3::new(3, 3, 1));
+        assert_eq!(v.yz1(), Vector3::new(2, 3, 1));
+    }
+
+    // --------------------------------------------------------------------------
+    // Setters
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn swizzle_set() {
+        let mut v = Vec2::new(1.0, 2.0);
+        v.set_yx(Vec2::new(5.0, 6.0));
+        assert_eq!(v, Vec2::new(6.0, 5.0));
+
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        v.set_zx(Vec2::new(7.0, 8.0));
+        assert_eq!(v, Vec3::new(8.0, 2.0, 7.0));
+        v.set_xy(Vec2::new(0.0, 0.0));
+        assert_eq!(v, Vec3::new(0.0, 0.0, 7.0));
+
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        v.set_xyz(Vec3::new(5.0, 6.0, 7.0));
+        assert_eq!(v, Vec4::new(5.0, 6.0, 7.0, 4.0));
+        v.set_wzyx(Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v, Vec4::new(4.0, 3.0, 2.0, 1.0));
+        v.set_wy(Vec2::new(0.0, 9.0));
+        assert_eq!(v, Vec4::new(4.0, 9.0, 2.0, 0.0));
+    }
+}
@@ -63,6 +63,32 @@ pub trait CwAbs<RHS = Self> {
     fn cw_abs(self) -> Self::Output;
 }
 
+// Component-wise comparisons. The output is a mask with one lane per component.
+pub trait CwLt<RHS = Self> {
+    type Output;
+    fn cw_lt(self, rhs: RHS) -> Self::Output;
+}
+
+pub trait CwLe<RHS = Self> {
+    type Output;
+    fn cw_le(self, rhs: RHS) -> Self::Output;
+}
+
+pub trait CwEq<RHS = Self> {
+    type Output;
+    fn cw_eq(self, rhs: RHS) -> Self::Output;
+}
+
+pub trait CwGt<RHS = Self> {
+    type Output;
+    fn cw_gt(self, rhs: RHS) -> Self::Output;
+}
+
+pub trait CwGe<RHS = Self> {
+    type Output;
+    fn cw_ge(self, rhs: RHS) -> Self::Output;
+}
+
 pub trait Elem<T> {
     type Output;
     fn elem(self, index: T) -> Self::Output;
@@ -75,11 +101,13 @@ pub trait ElemMut<T> {
 
 pub trait AsArray {
     type Output;
+    #[allow(clippy::wrong_self_convention)]
     fn as_array(self) -> Self::Output; // TODO(henk): How to design this in a way that defined the output as an array?
 }
 
 pub trait AsArrays {
     type Output;
+    #[allow(clippy::wrong_self_convention)]
     fn as_arrays(self) -> Self::Output; // TODO(henk): How to design this in a way that defined the output as an array?
 }
 
@@ -1,23 +1,45 @@
 use std::{
     ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg},
-    mem,
 };
 
-use crate::traits::*;
+use crate::{
+    traits::*,
+    backend,
+    mask::*,
+    angle::*,
+};
 
 use num_traits::Signed;
 use serde::{Serialize, Deserialize};
 
 pub use float_cmp::{Ulps,ApproxEq};
 
+// The dot product. With "backend" it is dispatched to the backend module by a hand-written impl
+// instead (see Vector4).
+macro_rules! implement_vector_kernels {
+    (scalar $type:ident { $($member:ident),+ }) => {
+        // v dot v
+        implement_binary_operator!(Dot<$type<T>> for $type<T>,
+            fn dot(lhs, rhs) -> T {{
+                let mut sum = T::zero();
+                $(sum += lhs.$member * rhs.$member;)*
+                sum
+            }}
+        );
+    };
+    (backend $type:ident { $($member:ident),+ }) => {};
+}
+
 macro_rules! implement_vector {
     ($type:ident {
         dim: $dim:expr,
+        kernels: $kernels:ident,
         elems: { $($num:expr => $member:ident),+ }
     }) => {
         //
         // DEFINE THE TYPE
         //
+        #[repr(C)]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
         pub struct $type<T> where T: Base {
             $(pub $member: T),*
@@ -46,10 +68,17 @@ macro_rules! implement_vector {
                 $type { $($member: T::one()),* }
             }
 
+            // Takes the components of if_true where the mask is set and those of if_false elsewhere.
+            pub fn select(mask: Mask<$dim>, if_true: Self, if_false: Self) -> Self {
+                $type { $($member: if mask.lanes[$num] { if_true.$member } else { if_false.$member }),* }
+            }
+
             // TODO(henk): Explain that one can use .as_array().clone() to create a new array.
             #[inline]
             pub fn as_array(&self) -> &[T; $dim] {
-                unsafe { mem::transmute(self) }
+                // SAFETY: The type is #[repr(C)] and all fields are of type T, so it has the same
+                // layout as [T; $dim].
+                unsafe { &*(self as *const Self as *const [T; $dim]) }
             }
         }
 
@@ -74,6 +103,188 @@ macro_rules! implement_vector {
             {
                 self / self.length()
             }
+
+            // None if the vector is too short (or too long) for its reciprocal length to be finite.
+            pub fn try_normalize(&self) -> Option<Self> {
+                let rcp = self.length().recip();
+                if rcp.is_finite() && rcp > T::zero() {
+                    Some(self * rcp)
+                } else {
+                    None
+                }
+            }
+
+            pub fn normalize_or_zero(&self) -> Self {
+                self.try_normalize().unwrap_or_else($type::zero)
+            }
+
+            #[inline]
+            pub fn length_squared(&self) -> T {
+                self.dot(self)
+            }
+
+            #[inline]
+            pub fn distance(&self, other: Self) -> T {
+                (other - self).length()
+            }
+
+            #[inline]
+            pub fn distance_squared(&self, other: Self) -> T {
+                (other - self).length_squared()
+            }
+
+            // Mirrors the vector at the plane with the unit normal n.
+            #[inline]
+            pub fn reflect(&self, n: Self) -> Self {
+                self - n * ((T::one() + T::one()) * self.dot(n))
+            }
+
+            // Refracts the unit incident vector at the surface with the unit normal n as in GLSL.
+            // eta is the ratio of the indices of refraction. Returns None on total internal
+            // reflection.
+            pub fn refract(&self, n: Self, eta: T) -> Option<Self> {
+                let d = self.dot(n);
+                let k = T::one() - eta * eta * (T::one() - d * d);
+                if k < T::zero() {
+                    return None;
+                }
+                Some(self * eta - n * (eta * d + k.sqrt()))
+            }
+
+            // The component parallel to other, which does not have to be normalized.
+            #[inline]
+            pub fn project_onto(&self, other: Self) -> Self {
+                other * (self.dot(other) / other.dot(other))
+            }
+
+            // The component perpendicular to other.
+            #[inline]
+            pub fn reject_from(&self, other: Self) -> Self {
+                self - self.project_onto(other)
+            }
+
+            // The unsigned angle in [0, pi]. Uses Kahan's formula, which unlike acos of the dot
+            // product stays accurate for nearly parallel vectors.
+            pub fn angle_between(&self, other: Self) -> Rad<T> {
+                let a = self * other.length();
+                let b = other * self.length();
+                let two = T::one() + T::one();
+                Rad(two * T::atan2((a - b).length(), (a + b).length()))
+            }
+        }
+
+        //
+        // COMPONENT-WISE FUNCTIONS
+        //
+        // The GLSL built-ins, applied to every component. Arguments of the vector type are
+        // used per component as well.
+        impl<T: Base> $type<T> {
+            #[inline]
+            pub fn cw_clamp(&self, min: Self, max: Self) -> Self {
+                self.cw_max(min).cw_min(max)
+            }
+
+            // 0 where the component is below the edge, 1 elsewhere.
+            #[inline]
+            pub fn cw_step(&self, edge: Self) -> Self {
+                $type::new($(if self.$member < edge.$member { T::zero() } else { T::one() }),*)
+            }
+        }
+
+        impl<T: Base + Neg<Output=T>> $type<T> {
+            // -1, 0 or 1. Unlike f32::signum, zero maps to zero.
+            #[inline]
+            pub fn cw_sign(&self) -> Self {
+                $type::new($(
+                    if self.$member > T::zero() { T::one() } else if self.$member < T::zero() { -T::one() } else { T::zero() }
+                ),*)
+            }
+        }
+
+        impl<T: BaseFloat> $type<T> {
+            #[inline]
+            pub fn cw_floor(&self) -> Self {
+                $type::new($(self.$member.floor()),*)
+            }
+
+            #[inline]
+            pub fn cw_ceil(&self) -> Self {
+                $type::new($(self.$member.ceil()),*)
+            }
+
+            // Rounds half-way cases away from zero.
+            #[inline]
+            pub fn cw_round(&self) -> Self {
+                $type::new($(self.$member.round()),*)
+            }
+
+            // x - floor(x) as in GLSL, so the result is in [0, 1) for negative components, too.
+            #[inline]
+            pub fn cw_fract(&self) -> Self {
+                $type::new($(self.$member - self.$member.floor()),*)
+            }
+
+            #[inline]
+            pub fn cw_sqrt(&self) -> Self {
+                $type::new($(self.$member.sqrt()),*)
+            }
+
+            #[inline]
+            pub fn cw_exp(&self) -> Self {
+                $type::new($(self.$member.exp()),*)
+            }
+
+            #[inline]
+            pub fn cw_ln(&self) -> Self {
+                $type::new($(self.$member.ln()),*)
+            }
+
+            #[inline]
+            pub fn cw_pow(&self, exponent: Self) -> Self {
+                $type::new($(self.$member.powf(exponent.$member)),*)
+            }
+
+            #[inline]
+            pub fn cw_recip(&self) -> Self {
+                $type::new($(self.$member.recip()),*)
+            }
+
+            #[inline]
+            pub fn cw_sin(&self) -> Self {
+                $type::new($(self.$member.sin()),*)
+            }
+
+            #[inline]
+            pub fn cw_cos(&self) -> Self {
+                $type::new($(self.$member.cos()),*)
+            }
+
+            // Hermite interpolation between 0 at edge0 and 1 at edge1.
+            pub fn cw_smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+                let two = T::one() + T::one();
+                let three = two + T::one();
+                $type::new($({
+                    let t = ((self.$member - edge0.$member) / (edge1.$member - edge0.$member)).max(T::zero()).min(T::one());
+                    t * t * (three - two * t)
+                }),*)
+            }
+
+            // self * a + b with a single rounding per component.
+            #[inline]
+            pub fn cw_fma(&self, a: Self, b: Self) -> Self {
+                $type::new($(self.$member.mul_add(a.$member, b.$member)),*)
+            }
+
+            // GLSL mix. Returns self for t = 0 and other for t = 1.
+            #[inline]
+            pub fn lerp(&self, other: Self, t: T) -> Self {
+                $type::new($(self.$member + (other.$member - self.$member) * t),*)
+            }
+
+            #[inline]
+            pub fn cw_lerp(&self, other: Self, t: Self) -> Self {
+                $type::new($(self.$member + (other.$member - self.$member) * t.$member),*)
+            }
         }
 
         //
@@ -243,14 +454,7 @@ macro_rules! implement_vector {
             }
         );
 
-        // v dot v
-        implement_binary_operator!(Dot<$type<T>> for $type<T>,
-            fn dot(lhs, rhs) -> T {{
-                let mut sum = T::zero();
-                $(sum += lhs.$member * rhs.$member;)*
-                sum
-            }}
-        );
+        implement_vector_kernels!($kernels $type { $($member),+ });
 
         implement_unary_operator!(Neg for $type<T> where T: Neg<Output=T>,
             fn neg(this) -> $type<T> {
@@ -265,6 +469,41 @@ macro_rules! implement_vector {
                 $type::new( $(this.$member.abs()),* )
             }
         );
+
+        // v cw_lt v
+        implement_binary_operator!(CwLt<$type<T>> for $type<T>,
+            fn cw_lt(lhs, rhs) -> Mask<$dim> {
+                Mask::new([$(lhs.$member < rhs.$member),*])
+            }
+        );
+
+        // v cw_le v
+        implement_binary_operator!(CwLe<$type<T>> for $type<T>,
+            fn cw_le(lhs, rhs) -> Mask<$dim> {
+                Mask::new([$(lhs.$member <= rhs.$member),*])
+            }
+        );
+
+        // v cw_eq v
+        implement_binary_operator!(CwEq<$type<T>> for $type<T>,
+            fn cw_eq(lhs, rhs) -> Mask<$dim> {
+                Mask::new([$(lhs.$member == rhs.$member),*])
+            }
+        );
+
+        // v cw_gt v
+        implement_binary_operator!(CwGt<$type<T>> for $type<T>,
+            fn cw_gt(lhs, rhs) -> Mask<$dim> {
+                Mask::new([$(lhs.$member > rhs.$member),*])
+            }
+        );
+
+        // v cw_ge v
+        implement_binary_operator!(CwGe<$type<T>> for $type<T>,
+            fn cw_ge(lhs, rhs) -> Mask<$dim> {
+                Mask::new([$(lhs.$member >= rhs.$member),*])
+            }
+        );
 /*
         implement_binary_operator!(Elem<usize> for $type<T>,
             fn elem(this, index) -> T {{
@@ -289,9 +528,9 @@ macro_rules! implement_vector {
     }
 }
 
-implement_vector!(Vector2 { dim: 2, elems: { 0 => x, 1 => y } });
-implement_vector!(Vector3 { dim: 3, elems: { 0 => x, 1 => y, 2 => z } });
-implement_vector!(Vector4 { dim: 4, elems: { 0 => x, 1 => y, 2 => z, 3 => w } });
+implement_vector!(Vector2 { dim: 2, kernels: scalar, elems: { 0 => x, 1 => y } });
+implement_vector!(Vector3 { dim: 3, kernels: scalar, elems: { 0 => x, 1 => y, 2 => z } });
+implement_vector!(Vector4 { dim: 4, kernels: backend, elems: { 0 => x, 1 => y, 2 => z, 3 => w } });
 
 implement_binary_operator!(Cross<Vector3<T>> for Vector3<T>,
     fn cross(a, b) -> Vector3<T> {
@@ -303,6 +542,45 @@ implement_binary_operator!(Cross<Vector3<T>> for Vector3<T>,
     }
 );
 
+// v dot v
+implement_binary_operator!(Dot<Vector4<T>> for Vector4<T>,
+    fn dot(lhs, rhs) -> T {
+        backend::vector4_dot(lhs, rhs)
+    }
+);
+
+impl<T: Base + Neg<Output=T>> Vector2<T> {
+    // The vector rotated by 90 degrees counterclockwise.
+    #[inline]
+    pub fn any_orthogonal(&self) -> Self {
+        Vector2::new(-self.y, self.x)
+    }
+}
+
+impl<T: BaseFloat> Vector3<T> {
+    // Some vector orthogonal to this one with a length in the same order of magnitude. Zero for
+    // the zero vector.
+    pub fn any_orthogonal(&self) -> Self {
+        if self.x.abs() > self.z.abs() {
+            Vector3::new(-self.y, self.x, T::zero())
+        } else {
+            Vector3::new(T::zero(), -self.z, self.y)
+        }
+    }
+
+    // Two unit vectors that form a right-handed orthonormal basis together with this one, which
+    // has to be normalized (Duff et al., "Building an Orthonormal Basis, Revisited").
+    pub fn orthonormal_basis(&self) -> (Self, Self) {
+        let sign = T::one().copysign(self.z);
+        let a = -T::one() / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vector3::new(T::one() + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vector3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+}
+
 impl<T: Base> Vector4<T> {
     pub fn wdiv(&self) -> Vector3<T> {
         Vector3::new(self.x/self.w, self.y/self.w, self.z/self.w)
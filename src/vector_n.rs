@@ -0,0 +1,382 @@
+use std::{
+    array,
+    fmt,
+    ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg},
+};
+
+use crate::{
+    traits::*,
+    vector::*,
+    mask::*,
+};
+
+use num_traits::Signed;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// A vector of arbitrary dimension. Vector2, Vector3 and Vector4 should be preferred for the
+// common sizes; this type is meant for code that is generic over the dimension and for larger
+// vectors such as blend-shape weights.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VectorN<T: Base, const N: usize> {
+    pub elems: [T; N],
+}
+
+impl<T: Base, const N: usize> VectorN<T, N> {
+    pub fn new(elems: [T; N]) -> Self {
+        VectorN { elems }
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        VectorN::new(array::from_fn(f))
+    }
+
+    #[inline]
+    pub fn dims() -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        VectorN::new([T::zero(); N])
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        VectorN::new([T::one(); N])
+    }
+
+    #[inline]
+    pub fn as_array(&self) -> &[T; N] {
+        &self.elems
+    }
+}
+
+impl<T: BaseFloat, const N: usize> VectorN<T, N> {
+    #[inline]
+    pub fn length(&self) -> T {
+        T::sqrt(self.dot(self))
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        self / self.length()
+    }
+}
+
+// Selection by the masks of the component-wise comparisons. Together with the wide vector types a
+// VectorN is used as a scalar per lane.
+impl<T: Base, const N: usize> VectorN<T, N> {
+    // Takes the elements of if_true where the mask is set and those of if_false elsewhere.
+    pub fn select(mask: Mask<N>, if_true: Self, if_false: Self) -> Self {
+        VectorN::from_fn(|i| if mask.lanes[i] { if_true.elems[i] } else { if_false.elems[i] })
+    }
+}
+
+impl<T: Base, const N: usize> Default for VectorN<T, N> {
+    fn default() -> Self {
+        VectorN::zero()
+    }
+}
+
+// --------------------------------------------------------------------------
+// Elem Trait
+// --------------------------------------------------------------------------
+
+impl<T: Base, const N: usize> Elem<usize> for VectorN<T, N> {
+    type Output = T;
+    #[inline]
+    fn elem(self, index: usize) -> Self::Output {
+        self.elems[index]
+    }
+}
+
+impl<'a, T: Base, const N: usize> Elem<usize> for &'a VectorN<T, N> {
+    type Output = &'a T;
+    #[inline]
+    fn elem(self, index: usize) -> Self::Output {
+        &self.elems[index]
+    }
+}
+
+impl<'a, T: Base, const N: usize> Elem<usize> for &'a mut VectorN<T, N> {
+    type Output = &'a mut T;
+    #[inline]
+    fn elem(self, index: usize) -> Self::Output {
+        &mut self.elems[index]
+    }
+}
+
+impl<T: BaseFloat, const N: usize> ApproxEq for VectorN<T, N>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.elems.iter().zip(other.elems.iter()).all(|(a, b)| a.approx_eq(b, epsilon, ulps))
+    }
+}
+
+// --------------------------------------------------------------------------
+// Operators
+// --------------------------------------------------------------------------
+
+// v + v
+implement_binary_operator!([const N: usize] Add<VectorN<T, N>> for VectorN<T, N>,
+    fn add(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| lhs.elems[i] + rhs.elems[i])
+    }
+);
+
+// v += v
+implement_binary_assign_operator!([const N: usize] AddAssign<VectorN<T, N>> for VectorN<T, N>,
+    fn add_assign(lhs, rhs) {{
+        for (a, b) in lhs.elems.iter_mut().zip(rhs.elems.iter()) { *a += *b; }
+    }}
+);
+
+// v - v
+implement_binary_operator!([const N: usize] Sub<VectorN<T, N>> for VectorN<T, N>,
+    fn sub(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| lhs.elems[i] - rhs.elems[i])
+    }
+);
+
+// v -= v
+implement_binary_assign_operator!([const N: usize] SubAssign<VectorN<T, N>> for VectorN<T, N>,
+    fn sub_assign(lhs, rhs) {{
+        for (a, b) in lhs.elems.iter_mut().zip(rhs.elems.iter()) { *a -= *b; }
+    }}
+);
+
+// v * s
+implement_binary_operator!([const N: usize] Mul<T> for VectorN<T, N>,
+    fn mul(vector, scalar) -> VectorN<T, N> {
+        VectorN::from_fn(|i| vector.elems[i] * scalar)
+    }
+);
+
+// v *= s
+implement_binary_assign_operator!([const N: usize] MulAssign<T> for VectorN<T, N>,
+    fn mul_assign(vector, scalar) {{
+        for a in vector.elems.iter_mut() { *a *= scalar; }
+    }}
+);
+
+// v * v
+implement_binary_operator!([const N: usize] Mul<VectorN<T, N>> for VectorN<T, N>,
+    fn mul(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| lhs.elems[i] * rhs.elems[i])
+    }
+);
+
+// v *= v
+implement_binary_assign_operator!([const N: usize] MulAssign<VectorN<T, N>> for VectorN<T, N>,
+    fn mul_assign(lhs, rhs) {{
+        for (a, b) in lhs.elems.iter_mut().zip(rhs.elems.iter()) { *a *= *b; }
+    }}
+);
+
+// v / s
+implement_binary_operator!([const N: usize] Div<T> for VectorN<T, N>,
+    fn div(vector, scalar) -> VectorN<T, N> {
+        VectorN::from_fn(|i| vector.elems[i] / scalar)
+    }
+);
+
+// v /= s
+implement_binary_assign_operator!([const N: usize] DivAssign<T> for VectorN<T, N>,
+    fn div_assign(vector, scalar) {{
+        for a in vector.elems.iter_mut() { *a /= scalar; }
+    }}
+);
+
+// v / v
+implement_binary_operator!([const N: usize] Div<VectorN<T, N>> for VectorN<T, N>,
+    fn div(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| lhs.elems[i] / rhs.elems[i])
+    }
+);
+
+// v /= v
+implement_binary_assign_operator!([const N: usize] DivAssign<VectorN<T, N>> for VectorN<T, N>,
+    fn div_assign(lhs, rhs) {{
+        for (a, b) in lhs.elems.iter_mut().zip(rhs.elems.iter()) { *a /= *b; }
+    }}
+);
+
+// v cw_min v
+implement_binary_operator!([const N: usize] CwMin<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_min(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| if lhs.elems[i] < rhs.elems[i] { lhs.elems[i] } else { rhs.elems[i] })
+    }
+);
+
+// v cw_max v
+implement_binary_operator!([const N: usize] CwMax<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_max(lhs, rhs) -> VectorN<T, N> {
+        VectorN::from_fn(|i| if rhs.elems[i] < lhs.elems[i] { lhs.elems[i] } else { rhs.elems[i] })
+    }
+);
+
+// v cw_lt v
+implement_binary_operator!([const N: usize] CwLt<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_lt(lhs, rhs) -> Mask<N> {
+        Mask::from_fn(|i| lhs.elems[i] < rhs.elems[i])
+    }
+);
+
+// v cw_le v
+implement_binary_operator!([const N: usize] CwLe<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_le(lhs, rhs) -> Mask<N> {
+        Mask::from_fn(|i| lhs.elems[i] <= rhs.elems[i])
+    }
+);
+
+// v cw_eq v
+implement_binary_operator!([const N: usize] CwEq<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_eq(lhs, rhs) -> Mask<N> {
+        Mask::from_fn(|i| lhs.elems[i] == rhs.elems[i])
+    }
+);
+
+// v cw_gt v
+implement_binary_operator!([const N: usize] CwGt<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_gt(lhs, rhs) -> Mask<N> {
+        Mask::from_fn(|i| lhs.elems[i] > rhs.elems[i])
+    }
+);
+
+// v cw_ge v
+implement_binary_operator!([const N: usize] CwGe<VectorN<T, N>> for VectorN<T, N>,
+    fn cw_ge(lhs, rhs) -> Mask<N> {
+        Mask::from_fn(|i| lhs.elems[i] >= rhs.elems[i])
+    }
+);
+
+// v dot v
+implement_binary_operator!([const N: usize] Dot<VectorN<T, N>> for VectorN<T, N>,
+    fn dot(lhs, rhs) -> T {{
+        let mut sum = T::zero();
+        for i in 0..N {
+            sum += lhs.elems[i] * rhs.elems[i];
+        }
+        sum
+    }}
+);
+
+implement_unary_operator!([const N: usize] Neg for VectorN<T, N> where T: Neg<Output=T>,
+    fn neg(this) -> VectorN<T, N> {
+        VectorN::from_fn(|i| -this.elems[i])
+    }
+);
+
+implement_unary_operator!([const N: usize] CwAbs for VectorN<T, N> where T: Signed,
+    fn cw_abs(this) -> VectorN<T, N> {
+        VectorN::from_fn(|i| this.elems[i].abs())
+    }
+);
+
+macro_rules! implement_specific_operators_for_vector_n {
+    ($($specific_type:ty),*) => {
+        $(
+            // s * v
+            implement_binary_operator_non_generic!([const N: usize] Mul<VectorN<$specific_type, N>> for $specific_type,
+                fn mul(scalar, vector) -> VectorN<$specific_type, N> {
+                    VectorN::from_fn(|i| scalar * vector.elems[i])
+                }
+            );
+
+            // s / v
+            implement_binary_operator_non_generic!([const N: usize] Div<VectorN<$specific_type, N>> for $specific_type,
+                fn div(scalar, vector) -> VectorN<$specific_type, N> {
+                    VectorN::from_fn(|i| scalar / vector.elems[i])
+                }
+            );
+        )*
+    }
+}
+
+implement_specific_operators_for_vector_n!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+// --------------------------------------------------------------------------
+// Serde
+// --------------------------------------------------------------------------
+
+// serde only implements its traits for arrays up to a fixed length, so the vector is written as a
+// tuple of its elements.
+impl<T: Base + Serialize, const N: usize> Serialize for VectorN<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self.elems, serializer)
+    }
+}
+
+impl<'de, T: Base + Deserialize<'de>, const N: usize> Deserialize<'de> for VectorN<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer, T::zero()).map(VectorN::new)
+    }
+}
+
+pub(crate) fn serialize_array<E: Serialize, S: Serializer, const N: usize>(array: &[E; N], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for e in array {
+        tuple.serialize_element(e)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize_array<'de, E, D, const N: usize>(deserializer: D, fill: E) -> Result<[E; N], D::Error>
+    where E: Copy + Deserialize<'de>, D: Deserializer<'de>
+{
+    struct ArrayVisitor<E, const N: usize> {
+        fill: E,
+    }
+
+    impl<'de, E: Copy + Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<E, N> {
+        type Value = [E; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a sequence of {} elements", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut array = [self.fill; N];
+            for (i, e) in array.iter_mut().enumerate() {
+                *e = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            Ok(array)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor { fill })
+}
+
+// --------------------------------------------------------------------------
+// Conversions
+// --------------------------------------------------------------------------
+
+macro_rules! implement_vector_n_conversion {
+    ($type:ident { dim: $dim:expr, elems: { $($num:expr => $member:ident),+ } }) => {
+        impl<T: Base> From<$type<T>> for VectorN<T, $dim> {
+            fn from(v: $type<T>) -> Self {
+                VectorN::new([$(v.$member),+])
+            }
+        }
+
+        impl<T: Base> From<VectorN<T, $dim>> for $type<T> {
+            fn from(v: VectorN<T, $dim>) -> Self {
+                $type::new($(v.elems[$num]),+)
+            }
+        }
+    }
+}
+
+implement_vector_n_conversion!(Vector2 { dim: 2, elems: { 0 => x, 1 => y } });
+implement_vector_n_conversion!(Vector3 { dim: 3, elems: { 0 => x, 1 => y, 2 => z } });
+implement_vector_n_conversion!(Vector4 { dim: 4, elems: { 0 => x, 1 => y, 2 => z, 3 => w } });
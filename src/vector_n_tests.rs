@@ -0,0 +1,102 @@
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use crate::vector_n::*;
+    use crate::vector::*;
+    use crate::traits::*;
+    use crate::mask::*;
+
+    type Vec3 = Vector3<f32>;
+    type Vec4 = Vector4<f32>;
+
+    type Vec5 = VectorN<f32, 5>;
+
+    #[test]
+    fn vector_n_new() {
+        let v = Vec5::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(Vec5::dims(), 5);
+        assert_eq!(v.elem(4), 5.0);
+        assert_eq!(v.as_array(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(Vec5::default(), Vec5::zero());
+        assert_eq!(Vec5::from_fn(|i| i as f32 + 1.0), v);
+    }
+
+    #[test]
+    fn vector_n_elem_mut() {
+        let mut v = Vec5::zero();
+        *(&mut v).elem(2) = 3.0;
+        assert_eq!(*(&v).elem(2), 3.0);
+    }
+
+    #[test]
+    fn vector_n_arithmetic() {
+        let a = Vec5::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = Vec5::one();
+        assert_eq!(a + b, Vec5::new([2.0, 3.0, 4.0, 5.0, 6.0]));
+        assert_eq!(&a - &b, Vec5::new([0.0, 1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(a * 2.0, Vec5::new([2.0, 4.0, 6.0, 8.0, 10.0]));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(a * a, Vec5::new([1.0, 4.0, 9.0, 16.0, 25.0]));
+        assert_eq!(a / 2.0, Vec5::new([0.5, 1.0, 1.5, 2.0, 2.5]));
+        assert_eq!(a / a, b);
+        assert_eq!(-b, Vec5::new([-1.0; 5]));
+    }
+
+    #[test]
+    fn vector_n_assign() {
+        let mut v = Vec5::one();
+        v += Vec5::one();
+        v *= 3.0;
+        v -= Vec5::one();
+        v /= Vec5::new([5.0; 5]);
+        assert_eq!(v, Vec5::one());
+    }
+
+    #[test]
+    fn vector_n_dot_length() {
+        let a = Vec5::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(a.dot(Vec5::one()), 15.0);
+        assert_eq!(VectorN::new([3.0, 0.0, 0.0, 0.0, 4.0]).length(), 5.0);
+        assert!(a.normalize().length().approx_eq(&1.0, f32::EPSILON, 2));
+    }
+
+    #[test]
+    fn vector_n_cw() {
+        let a = VectorN::new([1, -5, 3, 8, 0, 2]);
+        let b = VectorN::new([2, -6, 3, 1, 1, -2]);
+        assert_eq!(a.cw_min(b), VectorN::new([1, -6, 3, 1, 0, -2]));
+        assert_eq!(a.cw_max(b), VectorN::new([2, -5, 3, 8, 1, 2]));
+        assert_eq!(b.cw_abs(), VectorN::new([2, 6, 3, 1, 1, 2]));
+    }
+
+    #[test]
+    fn vector_n_approx_eq() {
+        let a = Vec5::new([0.1, 0.2, 0.3, 0.4, 0.5]);
+        let b = a * 3.0 / 3.0;
+        assert!(a.approx_eq(&b, f32::EPSILON, 2));
+        assert!(!a.approx_eq(&Vec5::zero(), f32::EPSILON, 2));
+    }
+
+    #[test]
+    fn vector_n_conversion() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let n: VectorN<f32, 3> = v.into();
+        assert_eq!(n, VectorN::new([1.0, 2.0, 3.0]));
+        assert_eq!(Vec3::from(n), v);
+        let w = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Vec4::from(VectorN::from(w)), w);
+        assert_eq!(VectorN::from(w).dot(VectorN::from(w)), w.dot(w));
+    }
+
+    #[test]
+    fn vector_n_compare_select() {
+        let a = VectorN::new([1, 2, 3, 4]);
+        let b = VectorN::new([4, 2, 1, 5]);
+        assert_eq!(a.cw_lt(b), Mask::new([true, false, false, true]));
+        assert_eq!(a.cw_le(b), Mask::new([true, true, false, true]));
+        assert_eq!(a.cw_gt(b), Mask::new([false, false, true, false]));
+        assert_eq!(a.cw_ge(b), Mask::new([false, true, true, false]));
+        assert_eq!(a.cw_eq(b), Mask::new([false, true, false, false]));
+        assert_eq!(VectorN::select(a.cw_lt(b), a, b), a.cw_min(b));
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use vector::*;
-    use traits::*;
+    use crate::vector::*;
+    use crate::traits::*;
+    use crate::mask::*;
 
     type Vec2 = Vector2<f32>;
     type Vec3 = Vector3<f32>;
@@ -23,41 +24,49 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_val_ref() {
         assert_eq!(Vec2::new(1.0, 2.0) + &Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_val_mutref() {
         assert_eq!(Vec2::new(1.0, 2.0) + &mut Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_ref_val() {
         assert_eq!(&Vec2::new(1.0, 2.0) + Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_ref_ref() {
         assert_eq!(&Vec2::new(1.0, 2.0) + &Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_ref_mutref() {
         assert_eq!(&Vec2::new(1.0, 2.0) + &mut Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_mutref_val() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) + Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_mutref_ref() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) + &Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_add_mutref_mutref() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) + &mut Vec2::new(2.0, 3.0), Vec2::new(3.0, 5.0));
     }
@@ -109,11 +118,13 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_val_ref() {
         assert_eq!(Vec2::new(1.0, 2.0) * &2.0, Vec2::new(2.0, 4.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_val_mutref() {
         assert_eq!(Vec2::new(1.0, 2.0) * &mut 2.0, Vec2::new(2.0, 4.0));
     }
@@ -124,11 +135,13 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_ref_ref() {
         assert_eq!(&Vec2::new(1.0, 2.0) * &2.0, Vec2::new(2.0, 4.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_ref_mutref() {
         assert_eq!(&Vec2::new(1.0, 2.0) * &mut 2.0, Vec2::new(2.0, 4.0));
     }
@@ -139,11 +152,13 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_mutref_ref() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) * &2.0, Vec2::new(2.0, 4.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvs_mutref_mutref() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) * &mut 2.0, Vec2::new(2.0, 4.0));
     }
@@ -179,11 +194,13 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvv_ref_ref() {
         assert_eq!(&Vec2::new(1.0, 2.0) * &Vec2::new(2.0, 4.0), Vec2::new(2.0, 8.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector2_mulvv_mutref_mutref() {
         assert_eq!(&mut Vec2::new(1.0, 2.0) * &mut Vec2::new(2.0, 4.0), Vec2::new(2.0, 8.0));
     }
@@ -309,9 +326,10 @@ mod tests {
     #[test]
     fn vector2_as_array2() {
         let mut v = Vec2::new(1.0, 2.0);
-        let a = v.as_array().clone();
+        let a = *v.as_array();
         v.x = 10.0;
         v.y = 20.0;
+        assert_eq!(v, Vec2::new(10.0, 20.0));
         assert_eq!(a[0], 1.0);
         assert_eq!(a[1], 2.0);
     }
@@ -322,8 +340,8 @@ mod tests {
 
     #[test]
     fn vector2_approx_eq() {
-        assert!(Vec2::new(1.0, 2.0).approx_eq(&Vec2::new(1.000000001, 2.000000001), ::std::f32::EPSILON, 2));
-        assert!(!Vec2::new(2.0, 2.0).approx_eq(&Vec2::new(1.000000001, 2.000000001), ::std::f32::EPSILON, 2));
+        assert!(Vec2::new(1.0, 2.0).approx_eq(&Vec2::new(1.000000001, 2.000000001), f32::EPSILON, 2));
+        assert!(!Vec2::new(2.0, 2.0).approx_eq(&Vec2::new(1.000000001, 2.000000001), f32::EPSILON, 2));
     }
 
     // --------------------------------------------------------------------------
@@ -377,31 +395,37 @@ mod tests {
     }
     
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_ref_val() {
         assert_eq!((&2.0)*Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_ref_ref() {
         assert_eq!((&2.0)*(&Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 4.0, 6.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_ref_mutref() {
         assert_eq!((&2.0)*(&mut Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 4.0, 6.0));
     }
     
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_mutref_val() {
         assert_eq!((&mut 2.0)*Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_mutref_ref() {
         assert_eq!((&mut 2.0)*(&Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 4.0, 6.0));
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn vector3_mulsv_mutref_mutref() {
         assert_eq!((&mut 2.0)*(&mut Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 4.0, 6.0));
     }
@@ -577,7 +601,7 @@ mod tests {
 
     #[test]
     fn vector3_elem_mut_ref() {
-        let ref mut v = Vec3::new(7.0, 8.0, 9.0);
+        let v = &mut Vec3::new(7.0, 8.0, 9.0);
         *v.elem(0) = 7.0;
         *v.elem(1) = 8.0;
         *v.elem(2) = 9.0;
@@ -621,4 +645,210 @@ mod tests {
         assert_eq!(Vec4::new(1.0, 2.0, 4.0, 2.0).wdiv(), Vec3::new(0.5, 1.0, 2.0));
     }
 
+    // --------------------------------------------------------------------------
+    //
+    // Component-wise comparisons
+    //
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn vector_cw_compare() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, 2.0, 1.0);
+        assert_eq!(a.cw_lt(b), BVec3::new([true, false, false]));
+        assert_eq!(a.cw_le(b), BVec3::new([true, true, false]));
+        assert_eq!(a.cw_eq(b), BVec3::new([false, true, false]));
+        assert_eq!(a.cw_gt(b), BVec3::new([false, false, true]));
+        assert_eq!(a.cw_ge(b), BVec3::new([false, true, true]));
+        assert_eq!(&a.cw_lt(b), &(&a).cw_lt(&b));
+        assert_eq!(Vector2::new(1, 5).cw_gt(Vector2::new(0, 5)), BVec2::new([true, false]));
+        assert_eq!(Vector4::new(1, 2, 3, 4).cw_eq(Vector4::new(1, 0, 3, 0)), BVec4::new([true, false, true, false]));
+    }
+
+    #[test]
+    fn vector_cw_compare_any_all() {
+        // Unlike the lexicographic PartialOrd, the masks reflect every component
+        let a = Vec3::new(0.0, 5.0, 0.0);
+        let b = Vec3::new(1.0, 1.0, 1.0);
+        assert!(a < b);
+        assert!(!a.cw_lt(b).all());
+        assert!(a.cw_lt(b).any());
+        assert!(Vec3::zero().cw_le(b).all());
+        assert!(Vec3::zero().cw_gt(b).none());
+    }
+
+    #[test]
+    fn vector_select() {
+        let a = Vec4::new(1.0, -2.0, 3.0, -4.0);
+        let b = Vec4::zero();
+        assert_eq!(Vec4::select(a.cw_gt(b), a, b), Vec4::new(1.0, 0.0, 3.0, 0.0));
+        assert_eq!(Vec4::select(a.cw_lt(b), a, b), a.cw_min(b));
+        assert_eq!(Vec2::select(BVec2::new([false, true]), Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)), Vec2::new(3.0, 2.0));
+    }
+
+    // --------------------------------------------------------------------------
+    //
+    // Component-wise functions
+    //
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn vector_cw_rounding() {
+        let v = Vec4::new(1.5, -1.5, 2.25, -0.75);
+        assert_eq!(v.cw_floor(), Vec4::new(1.0, -2.0, 2.0, -1.0));
+        assert_eq!(v.cw_ceil(), Vec4::new(2.0, -1.0, 3.0, -0.0));
+        assert_eq!(v.cw_round(), Vec4::new(2.0, -2.0, 2.0, -1.0));
+        assert_eq!(v.cw_fract(), Vec4::new(0.5, 0.5, 0.25, 0.25));
+    }
+
+    #[test]
+    fn vector_cw_clamp_sign_step() {
+        let v = Vec3::new(-2.0, 0.5, 3.0);
+        assert_eq!(v.cw_clamp(Vec3::zero(), Vec3::one()), Vec3::new(0.0, 0.5, 1.0));
+        assert_eq!(v.cw_sign(), Vec3::new(-1.0, 1.0, 1.0));
+        assert_eq!(Vec2::new(0.0, -0.0).cw_sign(), Vec2::zero());
+        assert_eq!(Vector3::new(-7, 0, 2).cw_sign(), Vector3::new(-1, 0, 1));
+        assert_eq!(Vector3::new(-7, 0, 2).cw_clamp(Vector3::new(-1, -1, -1), Vector3::new(1, 1, 1)), Vector3::new(-1, 0, 1));
+        assert_eq!(v.cw_step(Vec3::new(0.0, 0.5, 4.0)), Vec3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn vector_cw_exponential() {
+        let v = Vec2::new(4.0, 0.25);
+        assert_eq!(v.cw_sqrt(), Vec2::new(2.0, 0.5));
+        assert_eq!(v.cw_recip(), Vec2::new(0.25, 4.0));
+        assert_eq!(v.cw_pow(Vec2::new(0.5, 2.0)), Vec2::new(2.0, 0.0625));
+        assert!(v.cw_ln().cw_exp().approx_eq(&v, 4.0 * f32::EPSILON, 4));
+        assert_eq!(Vec2::zero().cw_exp(), Vec2::one());
+    }
+
+    #[test]
+    fn vector_cw_trigonometry() {
+        let v = Vec3::new(0.0, std::f32::consts::FRAC_PI_2, std::f32::consts::PI);
+        assert!(v.cw_sin().approx_eq(&Vec3::new(0.0, 1.0, 0.0), 4.0 * f32::EPSILON, 4));
+        assert!(v.cw_cos().approx_eq(&Vec3::new(1.0, 0.0, -1.0), 4.0 * f32::EPSILON, 4));
+    }
+
+    #[test]
+    fn vector_cw_smoothstep() {
+        let v = Vec4::new(-1.0, 0.0, 0.5, 2.0);
+        assert_eq!(v.cw_smoothstep(Vec4::zero(), Vec4::one()), Vec4::new(0.0, 0.0, 0.5, 1.0));
+        assert_eq!(Vec2::new(0.25, 0.75).cw_smoothstep(Vec2::zero(), Vec2::one()), Vec2::new(0.15625, 0.84375));
+    }
+
+    #[test]
+    fn vector_lerp_fma() {
+        let a = Vec3::new(0.0, 2.0, -4.0);
+        let b = Vec3::new(4.0, 2.0, 0.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.25), Vec3::new(1.0, 2.0, -3.0));
+        assert_eq!(a.cw_lerp(b, Vec3::new(0.0, 0.5, 0.5)), Vec3::new(0.0, 2.0, -2.0));
+        assert_eq!(a.cw_fma(b, Vec3::one()), Vec3::new(1.0, 5.0, 1.0));
+    }
+
+    // --------------------------------------------------------------------------
+    //
+    // Geometric helpers
+    //
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn vector_length_distance() {
+        let a = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(a.length_squared(), 9.0);
+        assert_eq!(a.distance(Vec3::new(1.0, 5.0, 6.0)), 5.0);
+        assert_eq!(a.distance_squared(Vec3::new(1.0, 5.0, 6.0)), 25.0);
+        assert_eq!(Vec4::new(1.0, 1.0, 1.0, 1.0).length_squared(), 4.0);
+        assert_eq!(Vec2::new(3.0, 0.0).distance(Vec2::new(0.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn vector_try_normalize() {
+        assert_eq!(Vec2::new(3.0, 4.0).try_normalize(), Some(Vec2::new(0.6, 0.8)));
+        assert_eq!(Vec3::zero().try_normalize(), None);
+        assert_eq!(Vec3::new(1e-15, 0.0, 0.0).try_normalize(), Some(Vec3::new(1.0, 0.0, 0.0)));
+        // The squared length underflows
+        assert_eq!(Vec3::new(1e-30, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec4::new(f32::INFINITY, 0.0, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec3::new(f32::NAN, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec3::zero().normalize_or_zero(), Vec3::zero());
+        assert_eq!(Vec3::new(0.0, 0.0, 2.0).normalize_or_zero(), Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn vector_reflect() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(Vec3::new(1.0, -1.0, 0.5).reflect(n), Vec3::new(1.0, 1.0, 0.5));
+        assert_eq!(Vec2::new(2.0, 3.0).reflect(Vec2::new(1.0, 0.0)), Vec2::new(-2.0, 3.0));
+    }
+
+    #[test]
+    fn vector_refract() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        let i = Vec3::new(1.0, -1.0, 0.0).normalize();
+        // No change in the medium keeps the direction
+        assert!(i.refract(n, 1.0).unwrap().approx_eq(&i, 4.0 * f32::EPSILON, 4));
+        // Snell's law: sin(out) = eta * sin(in)
+        let r = i.refract(n, 1.0 / 1.5).unwrap();
+        assert!(r.length().approx_eq(&1.0, 4.0 * f32::EPSILON, 4));
+        assert!(r.x.approx_eq(&(i.x / 1.5), 4.0 * f32::EPSILON, 4));
+        assert!(r.y < 0.0);
+        // Going into the thinner medium at 45 degrees reflects totally
+        assert!(i.refract(n, 1.5).is_none());
+    }
+
+    #[test]
+    fn vector_project_reject() {
+        let a = Vec3::new(2.0, 3.0, 4.0);
+        let b = Vec3::new(0.0, 2.0, 0.0);
+        assert_eq!(a.project_onto(b), Vec3::new(0.0, 3.0, 0.0));
+        assert_eq!(a.reject_from(b), Vec3::new(2.0, 0.0, 4.0));
+        assert_eq!(a.project_onto(b) + a.reject_from(b), a);
+        let v = Vec2::new(1.0, 3.0);
+        assert_eq!(v.reject_from(Vec2::new(1.0, 1.0)).dot(Vec2::new(1.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn vector_angle_between() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        assert!(x.angle_between(Vec3::new(0.0, 3.0, 0.0)).0.approx_eq(&FRAC_PI_2, 4.0 * f32::EPSILON, 4));
+        assert!(x.angle_between(Vec3::new(2.0, 2.0, 0.0)).0.approx_eq(&FRAC_PI_4, 4.0 * f32::EPSILON, 4));
+        assert!(x.angle_between(-x).0.approx_eq(&PI, 4.0 * f32::EPSILON, 4));
+        assert_eq!(x.angle_between(x * 5.0).0, 0.0);
+        // acos(dot) would return 0 here since the dot product rounds to 1
+        let angle = x.angle_between(Vec3::new(1.0, 1e-4, 0.0)).0;
+        assert!(angle.approx_eq(&1e-4, 1e-9, 4));
+        assert!(Vec2::new(1.0, 0.0).angle_between(Vec2::new(-1.0, 1.0)).0.approx_eq(&(3.0 * FRAC_PI_4), 4.0 * f32::EPSILON, 4));
+        assert!(Vec4::new(1.0, 0.0, 0.0, 0.0).angle_between(Vec4::new(0.0, 0.0, 0.0, 1.0)).0.approx_eq(&FRAC_PI_2, 4.0 * f32::EPSILON, 4));
+    }
+
+    #[test]
+    fn vector_any_orthogonal() {
+        assert_eq!(Vec2::new(1.0, 2.0).any_orthogonal(), Vec2::new(-2.0, 1.0));
+        assert_eq!(Vector2::new(3, 1).any_orthogonal(), Vector2::new(-1, 3));
+        for v in [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 2.0, 3.0), Vec3::new(-5.0, 0.1, 0.2)] {
+            let o = v.any_orthogonal();
+            assert_eq!(v.dot(o), 0.0);
+            assert!(o.length() > 0.0);
+        }
+    }
+
+    #[test]
+    fn vector_orthonormal_basis() {
+        for v in [Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, -1.0), Vec3::new(1.0, 2.0, 3.0), Vec3::new(-0.3, 0.2, -0.9)] {
+            let n = v.normalize();
+            let (b1, b2) = n.orthonormal_basis();
+            let eps = 4.0 * f32::EPSILON;
+            assert!(b1.length().approx_eq(&1.0, eps, 4));
+            assert!(b2.length().approx_eq(&1.0, eps, 4));
+            assert!(b1.dot(b2).approx_eq(&0.0, eps, 4));
+            assert!(b1.dot(n).approx_eq(&0.0, eps, 4));
+            assert!(b2.dot(n).approx_eq(&0.0, eps, 4));
+            // Right-handed
+            assert!(b1.cross(b2).approx_eq(&n, eps, 4));
+        }
+    }
+
 }
\ No newline at end of file
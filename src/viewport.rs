@@ -0,0 +1,151 @@
+use crate::{
+    traits::*,
+    vector::*,
+    matrix::*,
+    geometry::*,
+    clip_space::*,
+};
+
+use serde::{Serialize, Deserialize};
+
+// A window region in pixels with the origin in the lower left corner, as used by glViewport, and
+// the window depth range NDC depth is mapped to. For window coordinates with the origin in the
+// upper left corner (e.g. mouse positions) pass height - 1 - y as y.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Viewport<T: BaseFloat> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
+    pub min_depth: T,
+    pub max_depth: T,
+}
+
+impl<T: BaseFloat> Viewport<T> {
+    // A viewport with the window depth range [0, 1].
+    pub fn new(x: T, y: T, width: T, height: T) -> Self {
+        Viewport { x, y, width, height, min_depth: T::zero(), max_depth: T::one() }
+    }
+
+    pub fn ndc_to_window(&self, ndc: Vector3<T>) -> Vector3<T> {
+        self.ndc_to_window_with_clip_space(ndc, ClipSpace::OPENGL)
+    }
+
+    pub fn window_to_ndc(&self, window: Vector3<T>) -> Vector3<T> {
+        self.window_to_ndc_with_clip_space(window, ClipSpace::OPENGL)
+    }
+
+    // Maps the NDC depth range of the clip space to [min_depth, max_depth]. With reverse_z the near
+    // plane therefore ends up at max_depth.
+    pub fn ndc_to_window_with_clip_space(&self, ndc: Vector3<T>, clip: ClipSpace) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let (lo, hi) = clip.depth_range.bounds::<T>();
+        Vector3::new(
+            self.x + (ndc.x + T::one()) / two * self.width,
+            self.y + (ndc.y + T::one()) / two * self.height,
+            self.min_depth + (ndc.z - lo) / (hi - lo) * (self.max_depth - self.min_depth),
+        )
+    }
+
+    pub fn window_to_ndc_with_clip_space(&self, window: Vector3<T>, clip: ClipSpace) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let (lo, hi) = clip.depth_range.bounds::<T>();
+        Vector3::new(
+            two * (window.x - self.x) / self.width - T::one(),
+            two * (window.y - self.y) / self.height - T::one(),
+            (hi - lo) * (window.z - self.min_depth) / (self.max_depth - self.min_depth) + lo,
+        )
+    }
+}
+
+// The functions without suffix expect OpenGL clip space, i.e. NDC depth in [-1, 1].
+impl<T: BaseFloat> Matrix4<T> {
+    // Maps NDC to window coordinates like ndc_to_window.
+    pub fn new_viewport(viewport: &Viewport<T>) -> Self {
+        Self::new_viewport_with_clip_space(viewport, ClipSpace::OPENGL)
+    }
+
+    // Maps NDC to window coordinates like ndc_to_window_with_clip_space.
+    pub fn new_viewport_with_clip_space(viewport: &Viewport<T>, clip: ClipSpace) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        let (lo, hi) = clip.depth_range.bounds::<T>();
+        let (w, h, d) = (viewport.width * half, viewport.height * half, (viewport.max_depth - viewport.min_depth) / (hi - lo));
+        Matrix4::from_components_row_major(
+            w        , T::zero(), T::zero(), viewport.x + w,
+            T::zero(), h        , T::zero(), viewport.y + h,
+            T::zero(), T::zero(), d        , viewport.min_depth - lo * d,
+            T::zero(), T::zero(), T::zero(), T::one()
+        )
+    }
+
+    // Maps world to window coordinates with self being the view-projection matrix (gluProject).
+    pub fn project(&self, world: Vector3<T>, viewport: &Viewport<T>) -> Vector3<T> {
+        self.project_with_clip_space(world, viewport, ClipSpace::OPENGL)
+    }
+
+    pub fn project_with_clip_space(&self, world: Vector3<T>, viewport: &Viewport<T>, clip: ClipSpace) -> Vector3<T> {
+        let clip_pos = self * Vector4::new(world.x, world.y, world.z, T::one());
+        viewport.ndc_to_window_with_clip_space(clip_pos.wdiv(), clip)
+    }
+}
+
+impl<T: BaseFloat + ApproxEq<Flt=T>> Matrix4<T> {
+
+    // Maps window to world coordinates with self being the view-projection matrix (gluUnProject).
+    // Returns None if the matrix is not invertible.
+    pub fn unproject(&self, window: Vector3<T>, viewport: &Viewport<T>) -> Option<Vector3<T>> {
+        self.unproject_with_clip_space(window, viewport, ClipSpace::OPENGL)
+    }
+
+    pub fn unproject_with_clip_space(&self, window: Vector3<T>, viewport: &Viewport<T>, clip: ClipSpace) -> Option<Vector3<T>> {
+        Some(Self::unproject_homogeneous(&self.inverse()?, window, viewport, clip).wdiv())
+    }
+
+    // The ray through the given window position, starting on the near plane and pointing away from
+    // the camera. The direction is not normalized: it spans the frustum, i.e. point_at(1) is on the
+    // far plane.
+    pub fn pick_ray(&self, window: Vector2<T>, viewport: &Viewport<T>) -> Option<Ray3<T>> {
+        self.pick_ray_with_clip_space(window, viewport, ClipSpace::OPENGL)
+    }
+
+    // An infinite far plane unprojects to a point at infinity (w = 0). In that case point_at(1) is
+    // halfway through the NDC depth range instead.
+    pub fn pick_ray_with_clip_space(&self, window: Vector2<T>, viewport: &Viewport<T>, clip: ClipSpace) -> Option<Ray3<T>> {
+        let inv = self.inverse()?;
+        let unproject = |ndc_z| {
+            let depth = viewport.ndc_to_window_with_clip_space(Vector3::new(T::zero(), T::zero(), ndc_z), clip).z;
+            Self::unproject_homogeneous(&inv, Vector3::new(window.x, window.y, depth), viewport, clip)
+        };
+        let (ndc_near, ndc_far) = clip.depth_near_far::<T>();
+        let near = unproject(ndc_near).wdiv();
+        let far = unproject(ndc_far);
+        let far_max = far.x.abs().max(far.y.abs()).max(far.z.abs());
+        let far = if far.w.abs() > T::epsilon() * far_max {
+            far.wdiv()
+        } else {
+            unproject((ndc_near + ndc_far) / (T::one() + T::one())).wdiv()
+        };
+        Some(Ray3::new(near, far - near))
+    }
+
+    // Maps window to homogeneous world coordinates with inv being the inverse view-projection matrix.
+    fn unproject_homogeneous(inv: &Matrix4<T>, window: Vector3<T>, viewport: &Viewport<T>, clip: ClipSpace) -> Vector4<T> {
+        let ndc = viewport.window_to_ndc_with_clip_space(window, clip);
+        inv * Vector4::new(ndc.x, ndc.y, ndc.z, T::one())
+    }
+}
+
+impl<T: BaseFloat> ApproxEq for Viewport<T>
+    where T: ApproxEq<Flt=T>
+{
+    type Flt = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+        self.x.approx_eq(&other.x, epsilon, ulps) &&
+        self.y.approx_eq(&other.y, epsilon, ulps) &&
+        self.width.approx_eq(&other.width, epsilon, ulps) &&
+        self.height.approx_eq(&other.height, epsilon, ulps) &&
+        self.min_depth.approx_eq(&other.min_depth, epsilon, ulps) &&
+        self.max_depth.approx_eq(&other.max_depth, epsilon, ulps)
+    }
+}
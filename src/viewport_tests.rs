@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use crate::viewport::*;
+    use crate::matrix::*;
+    use crate::vector::*;
+    use crate::point::*;
+    use crate::clip_space::*;
+
+    type Mat4 = Matrix4<f32>;
+
+    type Vec2 = Vector2<f32>;
+    type Vec3 = Vector3<f32>;
+
+    fn viewport() -> Viewport<f32> {
+        Viewport::new(10.0, 20.0, 800.0, 600.0)
+    }
+
+    fn view_projection() -> Mat4 {
+        let projection = Mat4::new_perspective_from_fov(90.0, 800.0 / 600.0, 1.0, 100.0);
+        let view = Mat4::new_look_at(Vec3::new(0.0, 2.0, 5.0), Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        projection * view
+    }
+
+    fn all_clip_spaces() -> Vec<ClipSpace> {
+        let mut res = vec![];
+        for depth_range in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+            for handedness in [Handedness::Right, Handedness::Left] {
+                for reverse_z in [false, true] {
+                    res.push(ClipSpace { depth_range, handedness, reverse_z });
+                }
+            }
+        }
+        res
+    }
+
+    // The same camera as view_projection(), looking down the forward axis of the clip space.
+    fn view_projection_with_clip_space(clip: ClipSpace, z_far: f32) -> Mat4 {
+        let forward = if clip.handedness == Handedness::Right { -1.0 } else { 1.0 };
+        let projection = Mat4::new_perspective_from_fov_with_clip_space(90.0, 800.0 / 600.0, 1.0, z_far, clip);
+        let view = Mat4::new_look_at(Vec3::new(0.0, 2.0, 5.0), Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        // new_look_at builds a right-handed view, flip z for left-handed clip spaces
+        projection * Mat4::new_scale(1.0, 1.0, -forward) * view
+    }
+
+    // --------------------------------------------------------------------------
+    // Viewport
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn viewport_ndc_window() {
+        let v = viewport();
+        assert_eq!(v.ndc_to_window(Vec3::new(-1.0, -1.0, -1.0)), Vec3::new(10.0, 20.0, 0.0));
+        assert_eq!(v.ndc_to_window(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(810.0, 620.0, 1.0));
+        assert_eq!(v.ndc_to_window(Vec3::zero()), Vec3::new(410.0, 320.0, 0.5));
+        assert_eq!(v.window_to_ndc(Vec3::new(410.0, 320.0, 0.5)), Vec3::zero());
+    }
+
+    #[test]
+    fn viewport_ndc_window_with_clip_space() {
+        let v = Viewport { min_depth: 0.25, max_depth: 0.75, ..viewport() };
+        for clip in all_clip_spaces() {
+            let (lo, hi) = clip.depth_range.bounds::<f32>();
+            assert_eq!(v.ndc_to_window_with_clip_space(Vec3::new(-1.0, -1.0, lo), clip), Vec3::new(10.0, 20.0, 0.25));
+            assert_eq!(v.ndc_to_window_with_clip_space(Vec3::new(1.0, 1.0, hi), clip), Vec3::new(810.0, 620.0, 0.75));
+            let ndc = Vec3::new(0.5, -0.25, 0.5 * (lo + hi) + 0.125);
+            let back = v.window_to_ndc_with_clip_space(v.ndc_to_window_with_clip_space(ndc, clip), clip);
+            assert!(back.approx_eq(&ndc, 1e-6, 4), "{:?}", clip);
+            let m = Mat4::new_viewport_with_clip_space(&v, clip);
+            let p = m.transform_point(Point3::from_vector(ndc)).to_vector();
+            assert!(p.approx_eq(&v.ndc_to_window_with_clip_space(ndc, clip), 1e-4, 4), "{:?}", clip);
+        }
+    }
+
+    #[test]
+    fn matrix4_new_viewport() {
+        let v = Viewport { min_depth: 0.25, max_depth: 0.75, ..viewport() };
+        let m = Mat4::new_viewport(&v);
+        for ndc in [Vec3::new(-1.0, 1.0, -1.0), Vec3::new(0.5, -0.25, 0.5)] {
+            let p = m.transform_point(Point3::from_vector(ndc)).to_vector();
+            assert!(p.approx_eq(&v.ndc_to_window(ndc), 1e-4, 4));
+        }
+    }
+
+    // --------------------------------------------------------------------------
+    // Project / unproject
+    // --------------------------------------------------------------------------
+
+    #[test]
+    fn matrix4_project() {
+        let m = view_projection();
+        let v = viewport();
+        // Straight ahead ends up in the center of the viewport
+        let p = m.project(Vec3::new(0.0, 2.0, 0.0), &v);
+        assert!(Vec2::new(p.x, p.y).approx_eq(&Vec2::new(410.0, 320.0), 1e-4, 4));
+        // The near plane maps to the minimum depth
+        let p = m.project(Vec3::new(0.0, 2.0, 4.0), &v);
+        assert!(p.z.approx_eq(&0.0, 1e-5, 4));
+        // The right edge of the 90 degree field of view
+        let p = m.project(Vec3::new(5.0, 2.0, 0.0), &v);
+        assert!(p.x.approx_eq(&810.0, 1e-3, 4));
+    }
+
+    #[test]
+    fn matrix4_unproject_roundtrip() {
+        let m = view_projection();
+        let v = viewport();
+        for world in [Vec3::new(1.0, 2.5, -3.0), Vec3::new(-4.0, 0.0, -20.0), Vec3::new(0.5, 3.0, 2.0)] {
+            let window = m.project(world, &v);
+            let back = m.unproject(window, &v).unwrap();
+            assert!(back.approx_eq(&world, 1e-3, 4), "{:?} != {:?}", back, world);
+        }
+        assert!(Mat4::zero().unproject(Vec3::zero(), &v).is_none());
+    }
+
+    #[test]
+    fn matrix4_unproject_roundtrip_with_clip_space() {
+        let v = viewport();
+        for clip in all_clip_spaces() {
+            for &z_far in &[100.0, f32::INFINITY] {
+                let m = view_projection_with_clip_space(clip, z_far);
+                // The near plane maps to the near end of the clip space depth range
+                let (d_near, _) = clip.depth_near_far::<f32>();
+                let p = m.project_with_clip_space(Vec3::new(0.0, 2.0, 4.0), &v, clip);
+                let expected = v.ndc_to_window_with_clip_space(Vec3::new(0.0, 0.0, d_near), clip).z;
+                assert!(p.z.approx_eq(&expected, 1e-5, 4), "{:?}", clip);
+                for world in [Vec3::new(1.0, 2.5, -3.0), Vec3::new(-4.0, 0.0, -20.0), Vec3::new(0.5, 3.0, 2.0)] {
+                    let window = m.project_with_clip_space(world, &v, clip);
+                    let back = m.unproject_with_clip_space(window, &v, clip).unwrap();
+                    assert!(back.approx_eq(&world, 1e-3, 4), "{:?} {}: {:?} != {:?}", clip, z_far, back, world);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matrix4_pick_ray_with_clip_space() {
+        let v = viewport();
+        for clip in all_clip_spaces() {
+            for &z_far in &[100.0, f32::INFINITY] {
+                let m = view_projection_with_clip_space(clip, z_far);
+                let ray = m.pick_ray_with_clip_space(Vec2::new(410.0, 320.0), &v, clip).unwrap();
+                assert!(ray.origin.approx_eq(&Vec3::new(0.0, 2.0, 4.0), 1e-4, 4), "{:?} {}: {:?}", clip, z_far, ray);
+                assert!(ray.direction.normalize().approx_eq(&Vec3::new(0.0, 0.0, -1.0), 1e-5, 4), "{:?} {}: {:?}", clip, z_far, ray);
+                if z_far.is_finite() {
+                    // The camera is at z = 5, the far plane 100 units in front of it
+                    assert!(ray.point_at(1.0).approx_eq(&Vec3::new(0.0, 2.0, -95.0), 1e-2, 4), "{:?}: {:?}", clip, ray);
+                }
+
+                let world = Vec3::new(1.0, 3.0, -6.0);
+                let window = m.project_with_clip_space(world, &v, clip);
+                let ray = m.pick_ray_with_clip_space(Vec2::new(window.x, window.y), &v, clip).unwrap();
+                let t = (world - ray.origin).length() / ray.direction.length();
+                assert!(ray.point_at(t).approx_eq(&world, 1e-3, 4), "{:?} {}", clip, z_far);
+            }
+        }
+    }
+
+    #[test]
+    fn matrix4_pick_ray() {
+        let m = view_projection();
+        let v = viewport();
+        let ray = m.pick_ray(Vec2::new(410.0, 320.0), &v).unwrap();
+        assert!(ray.origin.approx_eq(&Vec3::new(0.0, 2.0, 4.0), 1e-4, 4));
+        assert!(ray.direction.normalize().approx_eq(&Vec3::new(0.0, 0.0, -1.0), 1e-5, 4));
+        // t in [0, 1] spans the frustum from the near to the far plane
+        assert!(ray.point_at(1.0).approx_eq(&Vec3::new(0.0, 2.0, -95.0), 1e-2, 4), "{:?}", ray);
+
+        // The ray passes through every point projected to the same pixel
+        let world = Vec3::new(1.0, 3.0, -6.0);
+        let window = m.project(world, &v);
+        let ray = m.pick_ray(Vec2::new(window.x, window.y), &v).unwrap();
+        let t = (world - ray.origin).length() / ray.direction.length();
+        assert!(ray.point_at(t).approx_eq(&world, 1e-3, 4));
+    }
+
+    #[test]
+    fn matrix4_pick_ray_large_coordinates() {
+        let projection = Mat4::new_perspective_from_fov(90.0, 800.0 / 600.0, 1.0, 100.0);
+        let v = viewport();
+        for &d in &[3000.0, 10000.0] {
+            let eye = Vec3::new(d, 2.0, d);
+            let m = projection * Mat4::new_look_at(eye, eye - Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 1.0, 0.0));
+            let world = eye + Vec3::new(1.0, 0.5, -10.0);
+            let back = m.unproject(m.project(world, &v), &v).unwrap();
+            assert!(back.approx_eq(&world, d * 1e-5, 4), "{:?} != {:?}", back, world);
+            let ray = m.pick_ray(Vec2::new(410.0, 320.0), &v).unwrap();
+            assert!(ray.direction.normalize().approx_eq(&Vec3::new(0.0, 0.0, -1.0), 1e-3, 4), "{:?}", ray);
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    array,
+    ops::{Add,AddAssign,Sub,SubAssign,Mul,MulAssign,Div,DivAssign,Neg},
+};
+
+use crate::{
+    traits::*,
+    vector::*,
+    vector_n::*,
+    mask::*,
+};
+
+use num_traits::Signed;
+use serde::{Serialize, Deserialize};
+
+pub use float_cmp::{Ulps,ApproxEq};
+
+// N vectors stored as structure of arrays: every component holds one value per lane. Scalars per
+// lane (e.g. the result of dot or length) are represented by VectorN<T, N>. Written against plain
+// arrays, the lane loops are simple enough for the compiler to vectorize.
+macro_rules! implement_wide_vector {
+    ($type:ident {
+        vector_type: $vector_type:ident,
+        elems: { $($member:ident),+ }
+    }) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+        pub struct $type<T: Base, const N: usize> {
+            $(pub $member: VectorN<T, N>),+
+        }
+
+        impl<T: Base, const N: usize> $type<T, N> {
+            pub fn new($($member: VectorN<T, N>),+) -> Self {
+                $type { $($member),+ }
+            }
+
+            // The same vector in every lane.
+            pub fn splat(v: $vector_type<T>) -> Self {
+                $type { $($member: VectorN::new([v.$member; N])),+ }
+            }
+
+            pub fn from_vectors(vectors: [$vector_type<T>; N]) -> Self {
+                $type { $($member: VectorN::from_fn(|i| vectors[i].$member)),+ }
+            }
+
+            pub fn to_vectors(&self) -> [$vector_type<T>; N] {
+                array::from_fn(|i| self.lane(i))
+            }
+
+            #[inline]
+            pub fn lanes() -> usize {
+                N
+            }
+
+            #[inline]
+            pub fn lane(&self, index: usize) -> $vector_type<T> {
+                $vector_type::new($(self.$member.elems[index]),+)
+            }
+
+            #[inline]
+            pub fn set_lane(&mut self, index: usize, v: $vector_type<T>) {
+                $(self.$member.elems[index] = v.$member;)+
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                $type { $($member: VectorN::zero()),+ }
+            }
+
+            #[inline]
+            pub fn one() -> Self {
+                $type { $($member: VectorN::one()),+ }
+            }
+
+            // Takes the lanes of if_true where the mask is set and those of if_false elsewhere.
+            pub fn select(mask: Mask<N>, if_true: Self, if_false: Self) -> Self {
+                $type { $($member: VectorN::select(mask, if_true.$member, if_false.$member)),+ }
+            }
+
+            pub fn length_squared(&self) -> VectorN<T, N> {
+                self.dot(self)
+            }
+        }
+
+        impl<T: BaseFloat, const N: usize> $type<T, N> {
+            #[inline]
+            pub fn length(&self) -> VectorN<T, N> {
+                let length_squared = self.length_squared();
+                VectorN::from_fn(|i| length_squared.elems[i].sqrt())
+            }
+
+            #[inline]
+            pub fn normalize(&self) -> Self {
+                self / self.length()
+            }
+        }
+
+        impl<T: Base, const N: usize> Default for $type<T, N> {
+            fn default() -> Self {
+                $type::zero()
+            }
+        }
+
+        impl<T: BaseFloat, const N: usize> ApproxEq for $type<T, N>
+            where T: ApproxEq<Flt=T>
+        {
+            type Flt = T;
+            #[inline]
+            fn approx_eq(&self, other: &Self, epsilon: T, ulps: <T as Ulps>::U) -> bool {
+                $(self.$member.approx_eq(&other.$member, epsilon, ulps))&&+
+            }
+        }
+
+        // v + v
+        implement_binary_operator!([const N: usize] Add<$type<T, N>> for $type<T, N>,
+            fn add(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member + rhs.$member),+)
+            }
+        );
+
+        // v += v
+        implement_binary_assign_operator!([const N: usize] AddAssign<$type<T, N>> for $type<T, N>,
+            fn add_assign(lhs, rhs) {{
+                $(lhs.$member += rhs.$member;)+
+            }}
+        );
+
+        // v - v
+        implement_binary_operator!([const N: usize] Sub<$type<T, N>> for $type<T, N>,
+            fn sub(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member - rhs.$member),+)
+            }
+        );
+
+        // v -= v
+        implement_binary_assign_operator!([const N: usize] SubAssign<$type<T, N>> for $type<T, N>,
+            fn sub_assign(lhs, rhs) {{
+                $(lhs.$member -= rhs.$member;)+
+            }}
+        );
+
+        // v * s
+        implement_binary_operator!([const N: usize] Mul<T> for $type<T, N>,
+            fn mul(vector, scalar) -> $type<T, N> {
+                $type::new($(vector.$member * scalar),+)
+            }
+        );
+
+        // v *= s
+        implement_binary_assign_operator!([const N: usize] MulAssign<T> for $type<T, N>,
+            fn mul_assign(vector, scalar) {{
+                $(vector.$member *= scalar;)+
+            }}
+        );
+
+        // v * s (one scalar per lane)
+        implement_binary_operator!([const N: usize] Mul<VectorN<T, N>> for $type<T, N>,
+            fn mul(vector, scalars) -> $type<T, N> {
+                $type::new($(vector.$member * scalars),+)
+            }
+        );
+
+        // v *= s (one scalar per lane)
+        implement_binary_assign_operator!([const N: usize] MulAssign<VectorN<T, N>> for $type<T, N>,
+            fn mul_assign(vector, scalars) {{
+                $(vector.$member *= scalars;)+
+            }}
+        );
+
+        // v * v
+        implement_binary_operator!([const N: usize] Mul<$type<T, N>> for $type<T, N>,
+            fn mul(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member * rhs.$member),+)
+            }
+        );
+
+        // v / s
+        implement_binary_operator!([const N: usize] Div<T> for $type<T, N>,
+            fn div(vector, scalar) -> $type<T, N> {
+                $type::new($(vector.$member / scalar),+)
+            }
+        );
+
+        // v /= s
+        implement_binary_assign_operator!([const N: usize] DivAssign<T> for $type<T, N>,
+            fn div_assign(vector, scalar) {{
+                $(vector.$member /= scalar;)+
+            }}
+        );
+
+        // v / s (one scalar per lane)
+        implement_binary_operator!([const N: usize] Div<VectorN<T, N>> for $type<T, N>,
+            fn div(vector, scalars) -> $type<T, N> {
+                $type::new($(vector.$member / scalars),+)
+            }
+        );
+
+        // v /= s (one scalar per lane)
+        implement_binary_assign_operator!([const N: usize] DivAssign<VectorN<T, N>> for $type<T, N>,
+            fn div_assign(vector, scalars) {{
+                $(vector.$member /= scalars;)+
+            }}
+        );
+
+        // v / v
+        implement_binary_operator!([const N: usize] Div<$type<T, N>> for $type<T, N>,
+            fn div(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member / rhs.$member),+)
+            }
+        );
+
+        // v cw_min v
+        implement_binary_operator!([const N: usize] CwMin<$type<T, N>> for $type<T, N>,
+            fn cw_min(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member.cw_min(rhs.$member)),+)
+            }
+        );
+
+        // v cw_max v
+        implement_binary_operator!([const N: usize] CwMax<$type<T, N>> for $type<T, N>,
+            fn cw_max(lhs, rhs) -> $type<T, N> {
+                $type::new($(lhs.$member.cw_max(rhs.$member)),+)
+            }
+        );
+
+        // v dot v
+        implement_binary_operator!([const N: usize] Dot<$type<T, N>> for $type<T, N>,
+            fn dot(lhs, rhs) -> VectorN<T, N> {{
+                let mut sum = VectorN::zero();
+                $(sum += lhs.$member * rhs.$member;)+
+                sum
+            }}
+        );
+
+        implement_unary_operator!([const N: usize] Neg for $type<T, N> where T: Neg<Output=T>,
+            fn neg(this) -> $type<T, N> {
+                $type::new($(-this.$member),+)
+            }
+        );
+
+        implement_unary_operator!([const N: usize] CwAbs for $type<T, N> where T: Signed,
+            fn cw_abs(this) -> $type<T, N> {
+                $type::new($(this.$member.cw_abs()),+)
+            }
+        );
+    }
+}
+
+implement_wide_vector!(WideVector2 { vector_type: Vector2, elems: { x, y } });
+implement_wide_vector!(WideVector3 { vector_type: Vector3, elems: { x, y, z } });
+implement_wide_vector!(WideVector4 { vector_type: Vector4, elems: { x, y, z, w } });
+
+pub type Vector2x4<T> = WideVector2<T, 4>;
+pub type Vector2x8<T> = WideVector2<T, 8>;
+pub type Vector3x4<T> = WideVector3<T, 4>;
+pub type Vector3x8<T> = WideVector3<T, 8>;
+pub type Vector4x4<T> = WideVector4<T, 4>;
+pub type Vector4x8<T> = WideVector4<T, 8>;
+
+implement_binary_operator!([const N: usize] Cross<WideVector3<T, N>> for WideVector3<T, N>,
+    fn cross(a, b) -> WideVector3<T, N> {
+        WideVector3::new(
+            a.y*b.z - a.z*b.y,
+            a.z*b.x - a.x*b.z,
+            a.x*b.y - a.y*b.x,
+        )
+    }
+);
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::wide::*;
+    use crate::vector::*;
+    use crate::vector_n::*;
+    use crate::mask::*;
+    use crate::traits::*;
+
+    type Vec3 = Vector3<f32>;
+
+    type Vec3x4 = Vector3x4<f32>;
+    type Vec3x8 = Vector3x8<f32>;
+
+    fn vectors() -> [Vec3; 4] {
+        [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 2.0, 2.0),
+            Vec3::new(-3.0, 0.0, 4.0),
+        ]
+    }
+
+    #[test]
+    fn wide_vector_lanes() {
+        let v = Vec3x4::from_vectors(vectors());
+        assert_eq!(Vec3x4::lanes(), 4);
+        assert_eq!(v.lane(2), Vec3::new(1.0, 2.0, 2.0));
+        assert_eq!(v.x, VectorN::new([1.0, 0.0, 1.0, -3.0]));
+        assert_eq!(v.to_vectors(), vectors());
+        let mut w = Vec3x4::zero();
+        w.set_lane(1, Vec3::new(5.0, 6.0, 7.0));
+        assert_eq!(w.lane(1), Vec3::new(5.0, 6.0, 7.0));
+        assert_eq!(w.lane(0), Vec3::zero());
+        assert_eq!(Vec3x8::splat(Vec3::one()).lane(7), Vec3::one());
+    }
+
+    #[test]
+    fn wide_vector_matches_vector3() {
+        let a = Vec3x4::from_vectors(vectors());
+        let b = Vec3x4::splat(Vec3::new(0.5, -1.0, 2.0));
+        let (dot, cross, min, max) = (a.dot(b), a.cross(b), a.cw_min(b), a.cw_max(b));
+        for (i, v) in vectors().iter().enumerate() {
+            let u = b.lane(i);
+            assert_eq!(dot.elems[i], v.dot(u));
+            assert_eq!(cross.lane(i), v.cross(u));
+            assert_eq!(min.lane(i), v.cw_min(u));
+            assert_eq!(max.lane(i), v.cw_max(u));
+            assert_eq!((a + b).lane(i), v + u);
+            assert_eq!((a - b).lane(i), v - u);
+            assert_eq!((-a).lane(i), -v);
+        }
+    }
+
+    #[test]
+    fn wide_vector_length_normalize() {
+        let v = Vec3x4::from_vectors(vectors());
+        assert_eq!(v.length(), VectorN::new([1.0, 2.0, 3.0, 5.0]));
+        assert_eq!(v.length_squared(), VectorN::new([1.0, 4.0, 9.0, 25.0]));
+        let n = v.normalize();
+        for i in 0..4 {
+            assert!(n.lane(i).approx_eq(&vectors()[i].normalize(), f32::EPSILON, 2));
+        }
+    }
+
+    #[test]
+    fn wide_vector_scalars() {
+        let v = Vec3x4::splat(Vec3::one());
+        let s = VectorN::new([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((v * s).lane(3), Vec3::new(4.0, 4.0, 4.0));
+        assert_eq!((v * 2.0).lane(0), Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!((v / s).lane(1), Vec3::new(0.5, 0.5, 0.5));
+        let mut w = v;
+        w *= s;
+        w /= 2.0;
+        w += v;
+        assert_eq!(w.lane(1), Vec3::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn wide_vector_select() {
+        let v = Vec3x4::from_vectors(vectors());
+        // Clamp all vectors to a maximum length of 2.
+        let length = v.length();
+        let too_long = length.cw_gt(VectorN::new([2.0; 4]));
+        assert_eq!(too_long, Mask::new([false, false, true, true]));
+        let clamped = Vec3x4::select(too_long, v.normalize() * 2.0, v);
+        assert_eq!(clamped.lane(0), vectors()[0]);
+        assert!(clamped.length().approx_eq(&VectorN::new([1.0, 2.0, 2.0, 2.0]), f32::EPSILON, 2));
+    }
+
+    #[test]
+    fn wide_vector_integer() {
+        let a = Vector3x8::splat(Vector3::new(1, -2, 3));
+        assert_eq!(a.cw_abs().lane(5), Vector3::new(1, 2, 3));
+        assert_eq!(a.dot(a), VectorN::new([14; 8]));
+    }
+}